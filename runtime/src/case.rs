@@ -0,0 +1,100 @@
+//! Drives the failure reports of parameterized `#[test_case]`s.
+
+use std::cell::RefCell;
+
+/// A parameterized test case, reported should the test panic.
+///
+/// Each input is listed by its source text, as written in the `#[test_case]`, along with its
+/// `Debug` value once bound - so the failing row of a table may be found immediately. Where the
+/// source text is unavailable (i.e. spans multiple tokens, on stable), only the value is listed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Case {
+    test: &'static str,
+    file: &'static str,
+    line: u32,
+    inputs: RefCell<Vec<CaseInput>>
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct CaseInput {
+    name: &'static str,
+    source: Option<&'static str>,
+    value: Option<String>
+}
+
+impl Case {
+    /// `inputs` are each input's name & source text (if available), in declaration order.
+    pub fn new(test: &'static str, file: &'static str, line: u32, inputs: &[(&'static str, Option<&'static str>)]) -> Self {
+        Self {
+            test, file, line,
+            inputs: RefCell::new(
+                inputs.iter().map(| &(name, source) | CaseInput { name, source, value: None }).collect()
+            )
+        }
+    }
+
+    /// Records the value bound to input `name`.
+    pub fn bind(&self, name: &'static str, value: String) {
+        if let Some(input) = self.inputs.borrow_mut().iter_mut().find(| input | input.name == name) {
+            input.value = Some(value);
+        }
+    }
+
+    /// Each bound input, by name, formatted via `Debug`.
+    pub fn values(&self) -> Vec<(&'static str, String)> {
+        self.inputs.borrow().iter()
+            .filter_map(| input | input.value.clone().map(| value | (input.name, value)))
+            .collect()
+    }
+
+    fn report(&self) -> String {
+        let mut report = format!("test case `{}` failed ({}:{})", self.test, self.file, self.line);
+        for input in self.inputs.borrow().iter() {
+            report.push_str(&format!("\n    {}", input.name));
+            if let Some(source) = input.source {
+                report.push_str(&format!(": {}", source));
+            }
+            if let Some(value) = &input.value {
+                report.push_str(&format!(" = {}", value));
+            }
+        }
+
+        report
+    }
+}
+
+impl Drop for Case {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            eprintln!("{}", self.report());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_lists_sources_and_bound_values() {
+        let case = Case::new("table_two", "tests/table.rs", 12, &[
+            ("value", Some("1 + 1")), ("r#type", Some("u8")), ("values", None)
+        ]);
+        case.bind("value", String::from("2"));
+        case.bind("values", String::from("[1, 2]"));
+
+        assert_eq!(case.values(), [("value", String::from("2")), ("values", String::from("[1, 2]"))]);
+        assert_eq!(
+            case.report(),
+            "test case `table_two` failed (tests/table.rs:12)\n    value: 1 + 1 = 2\n    r#type: u8\n    values = [1, 2]"
+        );
+    }
+
+    #[test]
+    fn bind_ignores_unknown_inputs() {
+        let case = Case::new("table", "tests/table.rs", 1, &[]);
+        case.bind("value", String::from("2"));
+
+        assert!(case.values().is_empty());
+    }
+}
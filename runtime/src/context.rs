@@ -0,0 +1,80 @@
+//! Metadata of the running test, as given by `sith::current_test!()`.
+
+use crate::filter;
+
+/// The test being run - and the case thereof, should it be a parameterized `#[test_case]`.
+///
+/// Obtained via `sith::current_test!()` within the test, or the `#[setup]`/`#[teardown]` of its
+/// suite - i.e. to name temporary directories or snapshots per case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TestContext {
+    module: &'static str,
+    name: &'static str,
+    suite: Option<&'static str>,
+    case: Option<&'static str>,
+    index: usize,
+    inputs: &'static [(&'static str, &'static str)],
+    tags: &'static [&'static str]
+}
+
+impl TestContext {
+    /// `module` is as given by `module_path!()`, and `inputs` each input's name & source text.
+    pub fn new(
+        module: &'static str, name: &'static str, suite: Option<&'static str>, case: Option<&'static str>,
+        index: usize, inputs: &'static [(&'static str, &'static str)], tags: &'static [&'static str]
+    ) -> Self {
+        Self { module, name, suite, case, index, inputs, tags }
+    }
+
+    /// The path to the test from the root of its crate, as named by libtest - i.e.
+    /// `suite::paint_small_red`.
+    pub fn qualified_name(&self) -> String {
+        filter::qualified_name(self.module, self.name)
+    }
+
+    /// The name of the test function generated, i.e. `paint_small_red`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The name of the enclosing `#[test_suite]` module, if any.
+    pub fn suite(&self) -> Option<&'static str> {
+        self.suite
+    }
+
+    /// The name of the case, as appended to that of the function - i.e. `small_red`, of
+    /// `#[test_case(small, with(each([Red, Blue])))]` - if any.
+    pub fn case(&self) -> Option<&'static str> {
+        self.case
+    }
+
+    /// The index of the case, amongst all those of the function - in declaration order.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Each input of `with(...)` - its name & source text, as written in the `#[test_case]`.
+    pub fn inputs(&self) -> &'static [(&'static str, &'static str)] {
+        self.inputs
+    }
+
+    /// The tags of the test, and those of its suite.
+    pub fn tags(&self) -> &'static [&'static str] {
+        self.tags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qualified_name_is_relative_to_crate() {
+        let context = TestContext::new(
+            "my_crate::suite", "paint_small_red", Some("suite"), Some("small_red"), 0, &[("color", "Red")], &[]
+        );
+
+        assert_eq!(context.qualified_name(), "suite::paint_small_red");
+        assert_eq!(context.inputs(), [("color", "Red")]);
+    }
+}
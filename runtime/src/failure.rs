@@ -0,0 +1,109 @@
+//! Drives `#[on_failure]` hooks of `#[test_suite]`s.
+
+use core::any::Any;
+use core::fmt::{
+    self, Debug, Display, Formatter
+};
+
+/// Describes a failed test, as passed to `#[on_failure]` hooks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    test: &'static str,
+    message: String,
+    inputs: Vec<(&'static str, String)>
+}
+
+impl Failure {
+    pub fn new(test: &'static str, payload: &(dyn Any + Send), inputs: Vec<(&'static str, String)>) -> Self {
+        Self { test, message: panic_message(payload), inputs }
+    }
+
+    /// The name of the failed test.
+    pub fn test(&self) -> &str {
+        self.test
+    }
+
+    /// The message the test panicked with.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Each input bound by `with(...)`, by name, formatted via `Debug`.
+    pub fn inputs(&self) -> &[(&'static str, String)] {
+        &self.inputs
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "test `{}` failed: {}", self.test, self.message)?;
+        for (name, value) in &self.inputs {
+            write!(f, "\n    {} = {}", name, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats values via `Debug` where implemented, see [`DescribeDebug`] & [`DescribeOpaque`].
+pub struct Described<'a, T: ?Sized>(pub &'a T);
+
+/// Preferred by method resolution when `T: Debug`: `(&Described(&value)).describe()`.
+pub trait DescribeDebug {
+    fn describe(&self) -> String;
+}
+
+impl<T: Debug + ?Sized> DescribeDebug for Described<'_, T> {
+    fn describe(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+/// Resolved via auto-ref otherwise, so values lacking `Debug` are still described.
+pub trait DescribeOpaque {
+    fn describe(&self) -> String;
+}
+
+impl<T: ?Sized> DescribeOpaque for &Described<'_, T> {
+    fn describe(&self) -> String {
+        format!("<{}>", core::any::type_name::<T>())
+    }
+}
+
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => String::from(*message),
+        (_, Some(message)) => message.clone(),
+        _ => String::from("Box<dyn Any>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Opaque;
+
+    #[test]
+    // Mirrors generated code - the borrow is what selects between implementations
+    #[allow(clippy::needless_borrow)]
+    fn describe_prefers_debug() {
+        assert_eq!((&Described(&"value")).describe(), "\"value\"");
+        assert_eq!((&Described(&Some(1))).describe(), "Some(1)");
+    }
+
+    #[test]
+    fn describe_falls_back_to_type_name() {
+        assert_eq!((&Described(&Opaque)).describe(), "<sith_runtime::failure::tests::Opaque>");
+    }
+
+    #[test]
+    fn new_extracts_panic_message() {
+        let payload = std::panic::catch_unwind(|| panic!("failed with {}", 1)).unwrap_err();
+        let failure = Failure::new("my_test", payload.as_ref(), vec![("a", String::from("1"))]);
+
+        assert_eq!(failure.test(), "my_test");
+        assert_eq!(failure.message(), "failed with 1");
+        assert_eq!(failure.to_string(), "test `my_test` failed: failed with 1\n    a = 1");
+    }
+}
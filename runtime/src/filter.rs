@@ -0,0 +1,144 @@
+//! Filters tests generated by sith at runtime - by their tags, via `SITH_TAGS`, and into
+//! shards, via `SITH_SHARD`.
+
+use std::env;
+
+use crate::skip;
+
+/// Whether `test` (of the module at `module`, as given by `module_path!()`) should be skipped,
+/// reporting such should it be.
+///
+/// `SITH_TAGS` is a comma-separated list of tags: tests must have any of those listed, and none
+/// of those prefixed with `!` - i.e. `SITH_TAGS=io,!slow`. Tests are run regardless, should it
+/// be unset or empty.
+///
+/// `SITH_SHARD=i/n` splits tests into `n` shards by a stable hash of their name, running only
+/// those of the `i`th (from 1), with those sharded out reported as such.
+///
+/// # Panics
+///
+/// If `SITH_SHARD` is set, but isn't of the form `i/n`.
+pub fn is_filtered(module: &str, test: &str, tags: &[&str]) -> bool {
+    is_filtered_by_tags(module, test, tags) || is_sharded_out(module, test)
+}
+
+fn is_filtered_by_tags(module: &str, test: &str, tags: &[&str]) -> bool {
+    let filter: String = env::var("SITH_TAGS").unwrap_or_default();
+    if filter.trim().is_empty() || matches(&filter, tags) {
+        return false;
+    }
+
+    let tags: String = match tags {
+        [] => String::from("untagged"),
+        tags => format!("tags `{}`", tags.join("`, `"))
+    };
+    skip::report(&qualified_name(module, test), &format!("{} don't match SITH_TAGS={}", tags, filter));
+
+    true
+}
+
+fn is_sharded_out(module: &str, test: &str) -> bool {
+    let Ok(shard) = env::var("SITH_SHARD") else {
+        return false;
+    };
+
+    let Some((index, count)) = parse_shard(&shard) else {
+        panic!("SITH_SHARD: expected `i/n` where 1 <= i <= n, found `{}`", shard);
+    };
+
+    let name: String = qualified_name(module, test);
+    let is_sharded_out: bool = hash(&name) % count != index - 1;
+    if is_sharded_out {
+        skip::report(&name, &format!("sharded out by SITH_SHARD={}", shard));
+    }
+
+    is_sharded_out
+}
+
+fn parse_shard(shard: &str) -> Option<(u64, u64)> {
+    let (index, count) = shard.split_once('/')?;
+    let (index, count) = (index.trim().parse::<u64>().ok()?, count.trim().parse::<u64>().ok()?);
+
+    (1..=count).contains(&index).then_some((index, count))
+}
+
+// FNV-1a - unlike `DefaultHasher`, stable between releases of Rust, so shards are too
+fn hash(name: &str) -> u64 {
+    name.bytes().fold(0xcbf29ce484222325, | hash, byte | {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+// As named by libtest - the path to the test, from the root of the crate
+pub(crate) fn qualified_name(module: &str, test: &str) -> String {
+    match module.split_once("::") {
+        Some((_, path)) => format!("{}::{}", path, test),
+        None => String::from(test)
+    }
+}
+
+fn matches(filter: &str, tags: &[&str]) -> bool {
+    let (excluded, included): (Vec<&str>, Vec<&str>) = filter.split(',')
+        .map(str::trim)
+        .filter(| term | !term.is_empty())
+        .partition(| term | term.starts_with('!'));
+
+    let is_excluded: bool = excluded.iter().any(| term | tags.contains(&&term[1..]));
+    let is_included: bool = included.is_empty() || included.iter().any(| term | tags.contains(term));
+
+    !is_excluded && is_included
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_included_tags() {
+        assert!(matches("io", &["slow", "io"]));
+        assert!(matches("db, io", &["io"]));
+        assert!(!matches("io", &["slow"]));
+        assert!(!matches("io", &[]));
+    }
+
+    #[test]
+    fn matches_excluded_tags() {
+        assert!(matches("!slow", &["io"]));
+        assert!(matches("!slow", &[]));
+        assert!(!matches("!slow", &["slow", "io"]));
+        assert!(!matches("io,!slow", &["slow", "io"]));
+    }
+
+    #[test]
+    fn parse_shard_accepts_one_based_index() {
+        assert_eq!(parse_shard("1/4"), Some((1, 4)));
+        assert_eq!(parse_shard("4/4"), Some((4, 4)));
+        assert_eq!(parse_shard("0/4"), None);
+        assert_eq!(parse_shard("5/4"), None);
+        assert_eq!(parse_shard("1/0"), None);
+        assert_eq!(parse_shard("1"), None);
+    }
+
+    #[test]
+    fn hash_is_stable() {
+        assert_eq!(hash(""), 0xcbf29ce484222325);
+        assert_eq!(hash("suite::test"), hash("suite::test"));
+        assert_ne!(hash("suite::test_0"), hash("suite::test_1"));
+    }
+
+    #[test]
+    fn hash_spreads_tests_across_shards() {
+        let mut shards: [usize; 4] = [0; 4];
+        for i in 0..400 {
+            shards[(hash(&format!("suite::test_{}", i)) % 4) as usize] += 1;
+        }
+
+        assert!(shards.iter().all(| &count | count > 50), "{:?}", shards);
+    }
+
+    #[test]
+    fn qualified_name_is_relative_to_crate() {
+        assert_eq!(qualified_name("my_crate::suite", "test"), "suite::test");
+        assert_eq!(qualified_name("my_crate", "test"), "test");
+    }
+}
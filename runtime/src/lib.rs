@@ -0,0 +1,46 @@
+//! Runtime support for tests generated by [sith](https://crates.io/crates/sith).
+//!
+//! Tests using runtime features of sith - such as `#[test_case(property)]`, or `with(...)`
+//! inputs reported should the test fail (under the `case-reports` feature) - expand to code
+//! referencing this crate, so it must be added as a `dev-dependency`:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! sith = "0.1.0"
+//! sith_runtime = "0.1.0"
+//! ```
+//!
+//! ```rust
+//! use sith_runtime::{Rng, Strategy, any};
+//!
+//! let mut rng = Rng::new(123);
+//! let value: Vec<u8> = any::<Vec<u8>>().generate(&mut rng);
+//! assert!(value.len() <= 32);
+//! ```
+
+mod rng;
+mod strategy;
+
+pub mod case;
+pub mod context;
+pub mod failure;
+pub mod filter;
+pub mod property;
+pub mod repeat;
+pub mod retry;
+pub mod sample;
+pub mod seed;
+pub mod serial;
+pub mod skip;
+pub mod timeout;
+pub mod xfail;
+
+pub use rng::Rng;
+pub use case::Case;
+pub use context::TestContext;
+pub use failure::Failure;
+pub use strategy::{
+    Strategy, Arbitrary,
+    Any, Just,
+    any, just
+};
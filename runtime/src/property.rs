@@ -0,0 +1,189 @@
+//! Drives tests generated by `#[test_case(property)]`.
+
+use core::cell::Cell;
+use core::fmt::Debug;
+use std::panic::{
+    self, AssertUnwindSafe
+};
+use std::sync::Once;
+
+use crate::{
+    Rng, Strategy,
+    failure::panic_message
+};
+
+// Upper bound on shrink candidates attempted, so pathological strategies still terminate
+const MAX_SHRINK_ATTEMPTS: usize = 4096;
+
+thread_local! {
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Results of a test body: tests may return `()` or a `Result`.
+pub trait Outcome {
+    /// The outcome of a passing test - i.e. `Ok(())`.
+    fn success() -> Self;
+
+    /// Describes the failure, if any - i.e. `Err(...)`.
+    fn failure(&self) -> Option<String>;
+}
+
+impl Outcome for () {
+    fn success() -> Self {}
+
+    fn failure(&self) -> Option<String> {
+        None
+    }
+}
+
+impl<E: Debug> Outcome for Result<(), E> {
+    fn success() -> Self {
+        Ok(())
+    }
+
+    fn failure(&self) -> Option<String> {
+        self.as_ref().err().map(| error | format!("Err({:?})", error))
+    }
+}
+
+/// Runs `test` against `cases` inputs generated by `strategy`. Upon failure, the failing
+/// input is shrunk to a minimal counterexample, which is reported along with the seed.
+///
+/// Seeded from `SITH_SEED` if set, so failures may be reproduced.
+///
+/// # Panics
+///
+/// If `test` fails for any input.
+pub fn check<S, F, R>(name: &str, inputs: &str, cases: u32, strategy: S, test: F) where
+    S: Strategy,
+    S::Value: Clone + Debug,
+    F: Fn(S::Value) -> R,
+    R: Outcome
+{
+    let seed: u64 = Rng::seed_from_env();
+    let mut rng: Rng = Rng::new(seed);
+
+    for case in 1..=cases {
+        let value: S::Value = strategy.generate(&mut rng);
+        let Err(failure) = run_silenced(&test, value.clone()) else {
+            continue;
+        };
+
+        let (minimal, failure, shrinks) = shrink(&strategy, &test, value, failure);
+        panic!(
+            "property `{}` failed after {} case(s) and {} shrink(s)\n    \
+            seed: {} (reproduce with SITH_SEED={})\n    \
+            minimal input: {} = {:?}\n    \
+            failure: {}",
+            name, case, shrinks,
+            seed, seed,
+            inputs, minimal,
+            failure
+        );
+    }
+}
+
+fn shrink<S, F, R>(strategy: &S, test: &F, mut value: S::Value, mut failure: String) -> (S::Value, String, usize) where
+    S: Strategy,
+    S::Value: Clone,
+    F: Fn(S::Value) -> R,
+    R: Outcome
+{
+    let (mut shrinks, mut attempts) = (0, 0);
+
+    'shrinking: while attempts < MAX_SHRINK_ATTEMPTS {
+        for candidate in strategy.shrink(&value) {
+            attempts += 1;
+            if let Err(candidate_failure) = run_silenced(test, candidate.clone()) {
+                (value, failure) = (candidate, candidate_failure);
+                shrinks += 1;
+
+                continue 'shrinking;
+            }
+
+            if attempts >= MAX_SHRINK_ATTEMPTS {
+                break 'shrinking;
+            }
+        }
+
+        // No simpler candidate fails - `value` is minimal
+        break;
+    }
+
+    (value, failure, shrinks)
+}
+
+// Panics are expected whilst searching for & shrinking counterexamples, so the panic
+// hook is silenced on this thread - tests running in parallel are unaffected. The hook in
+// place beforehand (the default, or any set by the tests) is kept, & delegated to for every
+// panic outside of such - hooks set afterwards replace this one, so aren't silenced
+fn run_silenced<V, F, R>(test: &F, value: V) -> Result<(), String> where
+    F: Fn(V) -> R,
+    R: Outcome
+{
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move | info | {
+            if !SILENCED.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+
+    SILENCED.with(| silenced | silenced.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| test(value).failure().map_or(Ok(()), Err)));
+    SILENCED.with(| silenced | silenced.set(false));
+
+    match result {
+        Ok(outcome) => outcome,
+        Err(payload) => Err(panic_message(payload.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::any;
+
+    #[test]
+    fn check_passes_when_property_holds() {
+        check("holds", "(a,)", 64, (any::<u8>(),), | (a,): (u8,) | {
+            assert!(a as u16 <= 255);
+        });
+    }
+
+    #[test]
+    fn check_reports_minimal_counterexample() {
+        let payload = panic::catch_unwind(|| {
+            check("fails", "(a, b)", 256, (0..1000u32, any::<bool>()), | (a, _): (u32, bool) | {
+                assert!(a < 100, "too large");
+            });
+        }).unwrap_err();
+
+        let message = panic_message(payload.as_ref());
+        assert!(message.contains("minimal input: (a, b) = (100, false)"), "{}", message);
+        assert!(message.contains("failure: too large"), "{}", message);
+        assert!(message.contains("SITH_SEED="), "{}", message);
+    }
+
+    #[test]
+    fn check_treats_errors_as_failures() {
+        let payload = panic::catch_unwind(|| {
+            check("errs", "(a,)", 64, (just_five(),), | (a,): (u8,) | {
+                match a {
+                    5 => Err("five"),
+                    _ => Ok(())
+                }
+            });
+        }).unwrap_err();
+
+        assert!(panic_message(payload.as_ref()).contains("failure: Err(\"five\")"));
+    }
+
+    fn just_five() -> crate::Just<u8> {
+        crate::just(5)
+    }
+}
@@ -0,0 +1,85 @@
+//! Drives `#[test_case(repeat(N))]`.
+
+use std::panic::{
+    self, AssertUnwindSafe
+};
+
+use crate::property::Outcome;
+
+/// Runs `body` `count` times, stopping at the first failed iteration - which is reported.
+///
+/// # Panics
+///
+/// If any iteration panics.
+pub fn run<F, R>(test: &str, count: u64, mut body: F) -> R where
+    F: FnMut() -> R,
+    R: Outcome
+{
+    let mut iteration: u64 = 0;
+
+    loop {
+        match panic::catch_unwind(AssertUnwindSafe(&mut body)) {
+            Ok(output) if output.failure().is_some() => {
+                report(test, iteration, count);
+                return output;
+            },
+            // At least one iteration is run, even if `count` is `0`
+            Ok(output) if iteration + 1 >= count => return output,
+            Ok(_) => iteration += 1,
+            Err(payload) => {
+                report(test, iteration, count);
+                panic::resume_unwind(payload);
+            }
+        }
+    }
+}
+
+fn report(test: &str, iteration: u64, count: u64) {
+    eprintln!("test `{}` failed on iteration {} (of {})", test, iteration, count);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::cell::Cell;
+
+    #[test]
+    fn run_repeats_body() {
+        let iterations: Cell<u64> = Cell::new(0);
+
+        run("repeated", 16, || iterations.set(iterations.get() + 1));
+        assert_eq!(iterations.get(), 16);
+    }
+
+    #[test]
+    fn run_stops_at_failed_iteration() {
+        let iterations: Cell<u64> = Cell::new(0);
+
+        let output = run("repeated", 16, || {
+            iterations.set(iterations.get() + 1);
+            match iterations.get() {
+                4 => Err("failed"),
+                _ => Ok(())
+            }
+        });
+
+        assert_eq!(output, Err("failed"));
+        assert_eq!(iterations.get(), 4);
+    }
+
+    #[test]
+    fn run_propagates_panics() {
+        let iterations: Cell<u64> = Cell::new(0);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            run("repeated", 16, || {
+                iterations.set(iterations.get() + 1);
+                assert!(iterations.get() < 2);
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(iterations.get(), 2);
+    }
+}
@@ -0,0 +1,72 @@
+//! Drives `#[test_case(retries = N)]`.
+
+use std::panic::{
+    self, AssertUnwindSafe
+};
+
+use crate::failure::panic_message;
+
+/// Runs `body`, re-running it up to `retries` times should it panic - passing if any attempt
+/// succeeds. Each failed attempt is reported, bar the last, which panics as usual.
+///
+/// Retries are disabled if `SITH_NO_RETRY` is set (to anything other than `0`).
+///
+/// # Panics
+///
+/// If every attempt panics.
+pub fn run<F, R>(test: &str, retries: u32, mut body: F) -> R where
+    F: FnMut() -> R
+{
+    let retries: u32 = if is_disabled() { 0 } else { retries };
+
+    for attempt in 1..=retries {
+        match panic::catch_unwind(AssertUnwindSafe(&mut body)) {
+            Ok(output) => return output,
+            Err(payload) => eprintln!(
+                "test `{}` failed attempt {} of {}, retrying: {}",
+                test, attempt, retries + 1, panic_message(payload.as_ref())
+            )
+        }
+    }
+
+    body()
+}
+
+fn is_disabled() -> bool {
+    std::env::var("SITH_NO_RETRY").is_ok_and(| value | !value.is_empty() && value != "0")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::cell::Cell;
+
+    #[test]
+    fn run_passes_if_any_attempt_succeeds() {
+        let attempts: Cell<u32> = Cell::new(0);
+
+        let output = run("flaky", 3, || {
+            attempts.set(attempts.get() + 1);
+            assert!(attempts.get() >= 3, "attempt {}", attempts.get());
+            attempts.get()
+        });
+
+        assert_eq!(output, 3);
+    }
+
+    #[test]
+    fn run_fails_if_every_attempt_fails() {
+        let attempts: Cell<u32> = Cell::new(0);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            run("broken", 2, || {
+                attempts.set(attempts.get() + 1);
+                panic!("broken");
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 3);
+    }
+}
@@ -0,0 +1,94 @@
+use std::hash::{
+    BuildHasher, Hasher
+};
+use std::collections::hash_map::RandomState;
+
+/// A small, deterministic pseudo-random number generator (SplitMix64).
+///
+/// Generated values depend only upon the seed, so failures may be reproduced
+/// by re-running with the seed reported (via `SITH_SEED`).
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Seeds from `SITH_SEED` if set, otherwise randomly.
+    ///
+    /// # Panics
+    ///
+    /// If `SITH_SEED` is set, but isn't an unsigned integer.
+    pub fn seed_from_env() -> u64 {
+        match std::env::var("SITH_SEED") {
+            Ok(seed) => seed.trim().parse::<u64>().unwrap_or_else(| _ | {
+                panic!("SITH_SEED must be an unsigned integer, found `{}`", seed)
+            }),
+            Err(_) => RandomState::new().build_hasher().finish()
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly samples from `0..bound`, returning `0` if `bound` is `0`.
+    pub fn below(&mut self, bound: u128) -> u128 {
+        if bound == 0 {
+            return 0;
+        }
+
+        let value = ((self.next_u64() as u128) << 64) | self.next_u64() as u128;
+        value % bound
+    }
+
+    /// Samples from `0.0..1.0`.
+    pub fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns `true` with a probability of `1 / n`.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.next_u64() % n.max(1) == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_deterministic_per_seed() {
+        let (mut left, mut right) = (Rng::new(7), Rng::new(7));
+
+        for _ in 0..16 {
+            assert_eq!(left.next_u64(), right.next_u64());
+        }
+    }
+
+    #[test]
+    fn below_is_within_bound() {
+        let mut rng = Rng::new(0);
+
+        for bound in 1..64 {
+            assert!(rng.below(bound) < bound);
+        }
+        assert_eq!(rng.below(0), 0);
+    }
+
+    #[test]
+    fn unit_is_within_range() {
+        let mut rng = Rng::new(0);
+
+        for _ in 0..64 {
+            let value = rng.unit();
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Drives `#[test_case(sample(N))]`, and sampling by `SITH_SAMPLE`.
+
+use std::env;
+
+use crate::{
+    Rng, skip,
+    filter::qualified_name
+};
+
+const ENV: &str = "SITH_SAMPLE";
+const SEED_ENV: &str = "SITH_SAMPLE_SEED";
+
+/// Whether case `index` of the `cases` expanded from a `#[test_case]` of `group` (the test
+/// function) should be skipped, reporting such - as `test`, of the module at `module` - should it be.
+///
+/// Of the cases, `count` are run - or the fraction given by `SITH_SAMPLE`, which takes precedence,
+/// so all may be run by `SITH_SAMPLE=1`. Those run are selected by `SITH_SAMPLE_SEED` (0 if unset)
+/// and `group`, so remain the same between runs until either changes.
+///
+/// # Panics
+///
+/// If `SITH_SAMPLE` is set, but isn't a fraction within `(0, 1]` - or `SITH_SAMPLE_SEED` is set,
+/// but isn't an unsigned integer.
+pub fn is_sampled_out(module: &str, test: &str, group: &str, index: usize, cases: usize, count: Option<usize>) -> bool {
+    let fraction: Option<String> = env::var(ENV).ok().filter(| fraction | !fraction.trim().is_empty());
+    let (count, source) = match (fraction, count) {
+        (Some(fraction), _) => {
+            let Some(parsed) = parse_fraction(&fraction) else {
+                panic!("{}: expected a fraction within (0, 1], i.e. `0.1` - found `{}`", ENV, fraction);
+            };
+
+            ((cases as f64 * parsed).ceil() as usize, format!("{}={}", ENV, fraction.trim()))
+        },
+        (None, Some(count)) => (count, format!("sample({})", count)),
+        (None, None) => {
+            return false;
+        }
+    };
+
+    let seed: u64 = match env::var(SEED_ENV) {
+        Ok(seed) => seed.trim().parse::<u64>().unwrap_or_else(| _ | {
+            panic!("{}: expected an unsigned integer, found `{}`", SEED_ENV, seed)
+        }),
+        Err(_) => 0
+    };
+
+    let count: usize = count.clamp(1, cases.max(1));
+    if selected(seed, group, cases, count).contains(&index) {
+        return false;
+    }
+
+    skip::report(&qualified_name(module, test), &format!(
+        "sampled out, {} of {} cases run by {} with {}={} (run with {}=1 to run all)",
+        count, cases, source, SEED_ENV, seed, ENV
+    ));
+
+    true
+}
+
+fn parse_fraction(fraction: &str) -> Option<f64> {
+    fraction.trim().parse::<f64>().ok().filter(| fraction | *fraction > 0.0 && *fraction <= 1.0)
+}
+
+// The indices of the `count` of `cases` run, as shuffled by the seed & name of the test
+fn selected(seed: u64, group: &str, cases: usize, count: usize) -> Vec<usize> {
+    let seed: u64 = group.bytes().fold(seed ^ 0xcbf29ce484222325, | hash, byte | {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+
+    let mut rng: Rng = Rng::new(seed);
+    let mut indices: Vec<usize> = (0..cases).collect();
+    for i in 0..count {
+        indices.swap(i, i + rng.below((cases - i) as u128) as usize);
+    }
+
+    indices.truncate(count);
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_fraction_accepts_unit_interval() {
+        assert_eq!(parse_fraction("0.1"), Some(0.1));
+        assert_eq!(parse_fraction(" 1 "), Some(1.0));
+        assert_eq!(parse_fraction("0"), None);
+        assert_eq!(parse_fraction("1.5"), None);
+        assert_eq!(parse_fraction("half"), None);
+    }
+
+    #[test]
+    fn selected_is_deterministic_per_seed() {
+        let selected: Vec<usize> = selected(0, "test", 100, 10);
+
+        let mut unique: Vec<usize> = selected.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(unique.len(), 10);
+        assert!(unique.iter().all(| &index | index < 100));
+
+        assert_eq!(selected, super::selected(0, "test", 100, 10));
+        assert_ne!(selected, super::selected(1, "test", 100, 10));
+        assert_ne!(selected, super::selected(0, "other_test", 100, 10));
+    }
+}
@@ -0,0 +1,91 @@
+//! Drives seeds bound by `#[test_case(seed)]` & `#[test_suite(seed)]`.
+
+use crate::{
+    Rng,
+    property::Outcome
+};
+
+/// A per-test seed, reported should the test panic or fail.
+///
+/// Panics are reported upon drop whilst unwinding, rather than via a panic hook - so
+/// any hook installed by the test (or harness) is left untouched, and tests running
+/// in parallel needn't contend over such. Failing outputs are reported by `check`.
+#[derive(Debug)]
+pub struct Seed {
+    test: &'static str,
+    value: u64
+}
+
+impl Seed {
+    /// Seeds `test` from `SITH_SEED` if set, otherwise randomly.
+    ///
+    /// # Panics
+    ///
+    /// If `SITH_SEED` is set, but isn't an unsigned integer.
+    pub fn new(test: &'static str) -> Self {
+        Self::with_value(test, Rng::seed_from_env())
+    }
+
+    pub fn with_value(test: &'static str, value: u64) -> Self {
+        Self { test, value }
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Passes through the output of the test, reporting the seed should it fail - i.e. `Err(...)`.
+    pub fn check<R: Outcome>(&self, output: R) -> R {
+        if output.failure().is_some() {
+            eprintln!("{}", self.report());
+        }
+
+        output
+    }
+
+    fn report(&self) -> String {
+        format!(
+            "test `{}` failed with seed: {} (reproduce with SITH_SEED={})",
+            self.test, self.value, self.value
+        )
+    }
+}
+
+impl Drop for Seed {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            eprintln!("{}", self.report());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_includes_test_and_seed() {
+        let seed = Seed::with_value("my_test", 123);
+
+        assert_eq!(seed.value(), 123);
+        assert_eq!(seed.report(), "test `my_test` failed with seed: 123 (reproduce with SITH_SEED=123)");
+    }
+
+    #[test]
+    fn drop_whilst_unwinding_is_reported() {
+        let result = std::panic::catch_unwind(|| {
+            let _seed = Seed::with_value("my_test", 123);
+            panic!("failed");
+        });
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn check_passes_through_output() {
+        let seed = Seed::with_value("my_test", 123);
+
+        assert_eq!(seed.check(Ok::<(), &str>(())), Ok(()));
+        assert_eq!(seed.check(Err::<(), &str>("failed")), Err("failed"));
+    }
+}
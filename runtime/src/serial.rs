@@ -0,0 +1,53 @@
+//! Drives `#[test_case(serial)]` & `#[test_suite(serial)]`.
+
+use std::{
+    collections::BTreeMap,
+    sync::{
+        Mutex, MutexGuard, PoisonError
+    }
+};
+
+static GROUPS: Mutex<BTreeMap<&'static str, &'static Mutex<()>>> = Mutex::new(BTreeMap::new());
+
+/// Blocks until no other test of `group` is running, returning a guard to be held for the
+/// duration of the test. Serial tests without a group form the unnamed group, `""`.
+///
+/// Should a test panic whilst holding the guard, the lock is poisoned - which is ignored,
+/// so the failure doesn't cascade into the tests that follow.
+pub fn lock(group: &'static str) -> MutexGuard<'static, ()> {
+    let mutex: &'static Mutex<()> = GROUPS.lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(group)
+        .or_insert_with(|| Box::leak(Box::new(Mutex::new(()))));
+
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lock_is_shared_within_group() {
+        let _guard = lock("shared");
+
+        assert!(GROUPS.lock().unwrap()["shared"].try_lock().is_err());
+    }
+
+    #[test]
+    fn lock_is_independent_between_groups() {
+        let _guard = lock("first");
+        let _other = lock("second");
+    }
+
+    #[test]
+    fn lock_recovers_from_poisoning() {
+        let result = std::thread::spawn(|| {
+            let _guard = lock("poisoned");
+            panic!("failed");
+        }).join();
+
+        assert!(result.is_err());
+        let _guard = lock("poisoned");
+    }
+}
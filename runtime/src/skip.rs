@@ -0,0 +1,79 @@
+//! Drives `skip_if(...)` & `requires_env(...)` of `#[test_case]` & `#[test_suite]`.
+
+use std::{
+    env,
+    io::Write
+};
+
+/// Returned by a skipped test - which passes, as libtest has no runtime-skip status.
+pub trait Skipped {
+    fn skipped() -> Self;
+}
+
+impl Skipped for () {
+    fn skipped() -> Self {}
+}
+
+impl<E> Skipped for Result<(), E> {
+    fn skipped() -> Self {
+        Ok(())
+    }
+}
+
+/// Reports `test` as skipped should `condition` hold, returning such.
+pub fn skip_if(test: &str, condition: bool, reason: &str) -> bool {
+    if condition {
+        report(test, reason);
+    }
+
+    condition
+}
+
+/// Reports `test` as skipped should any of the environment variables `names` be unset
+/// (or empty), returning such.
+pub fn requires_env(test: &str, names: &[&str]) -> bool {
+    let missing: Vec<String> = names.iter()
+        .filter(| name | env::var_os(name).map_or(true, | value | value.is_empty()))
+        .map(| name | format!("`{}`", name))
+        .collect();
+
+    if missing.is_empty() {
+        return false;
+    }
+
+    report(test, &format!("requires {} to be set", missing.join(", ")));
+    true
+}
+
+/// Prints `test `...` skipped: <reason>` - written to stderr directly, rather than
+/// via `eprintln!`, so it isn't captured by libtest (which only shows such for failures).
+pub fn report(test: &str, reason: &str) {
+    let _ = writeln!(std::io::stderr(), "{}", message(test, reason));
+}
+
+fn message(test: &str, reason: &str) -> String {
+    format!("test `{}` skipped: {}", test, reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skip_if_returns_condition() {
+        assert!(skip_if("my_test", true, "reason"));
+        assert!(!skip_if("my_test", false, "reason"));
+    }
+
+    #[test]
+    fn requires_env_skips_when_unset() {
+        assert!(requires_env("my_test", &["SITH_TEST_UNSET_VAR"]));
+        assert!(!requires_env("my_test", &["PATH"]));
+        assert!(!requires_env("my_test", &[]));
+    }
+
+    #[test]
+    fn message_includes_test_and_reason() {
+        assert_eq!(message("my_test", "no fixtures"), "test `my_test` skipped: no fixtures");
+    }
+}
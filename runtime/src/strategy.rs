@@ -0,0 +1,417 @@
+use core::marker::PhantomData;
+use core::ops::{
+    Range, RangeInclusive
+};
+
+use crate::Rng;
+
+// Bounds the size of generated collections, keeping individual cases fast
+const MAX_LEN: u128 = 32;
+
+/// Generates - and shrinks - values of a type.
+///
+/// Ranges of integers are strategies themselves, i.e `0..100u8`, as are tuples of strategies.
+pub trait Strategy {
+    type Value;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    /// Simpler variants of `value`, most aggressive first. Shrinking repeatedly
+    /// substitutes the first candidate still failing, until none do.
+    fn shrink(&self, _value: &Self::Value) -> Vec<Self::Value> {
+        Vec::new()
+    }
+}
+
+/// Types that may be generated without further configuration, see [`any`].
+pub trait Arbitrary: Sized {
+    fn arbitrary(rng: &mut Rng) -> Self;
+
+    fn shrink(&self) -> Vec<Self> {
+        Vec::new()
+    }
+}
+
+/// Strategy generating any value of `T`, see [`any`].
+pub struct Any<T>(PhantomData<fn() -> T>);
+
+impl<T: Arbitrary> Strategy for Any<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        T::arbitrary(rng)
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        value.shrink()
+    }
+}
+
+/// Generates any value of `T`, i.e `any::<u32>()`.
+pub fn any<T: Arbitrary>() -> Any<T> {
+    Any(PhantomData)
+}
+
+/// Strategy always generating the same value, see [`just`].
+#[derive(Clone, Debug)]
+pub struct Just<T>(pub T);
+
+impl<T: Clone> Strategy for Just<T> {
+    type Value = T;
+
+    fn generate(&self, _: &mut Rng) -> T {
+        self.0.clone()
+    }
+}
+
+/// Always generates `value`, i.e to fix a single input of a property test.
+pub fn just<T: Clone>(value: T) -> Just<T> {
+    Just(value)
+}
+
+// Candidates between `value` and `target`, halving the distance each step, i.e `100 -> [0, 50, 75, 88, ...]`
+fn shrink_towards(value: i128, target: i128) -> Vec<i128> {
+    let mut candidates: Vec<i128> = Vec::new();
+    let mut delta = value - target;
+
+    while delta != 0 {
+        candidates.push(value - delta);
+        delta /= 2;
+    }
+
+    candidates
+}
+
+macro_rules! impl_int {
+    ($($ty:ty),+) => {
+        $(
+            impl Arbitrary for $ty {
+                fn arbitrary(rng: &mut Rng) -> Self {
+                    // Favour edge cases, which uniform sampling would seldom produce
+                    if rng.one_in(8) {
+                        let edges = [0, 1, <$ty>::MIN, <$ty>::MAX];
+                        return edges[rng.below(edges.len() as u128) as usize];
+                    }
+
+                    rng.next_u64() as $ty
+                }
+
+                fn shrink(&self) -> Vec<Self> {
+                    shrink_towards(*self as i128, 0).into_iter().map(| value | value as $ty).collect()
+                }
+            }
+
+            impl Strategy for Range<$ty> {
+                type Value = $ty;
+
+                fn generate(&self, rng: &mut Rng) -> $ty {
+                    assert!(!self.is_empty(), "cannot generate values from an empty range");
+                    let span = (self.end as i128 - self.start as i128) as u128;
+                    (self.start as i128 + rng.below(span) as i128) as $ty
+                }
+
+                fn shrink(&self, value: &$ty) -> Vec<$ty> {
+                    shrink_towards(*value as i128, self.start as i128).into_iter().map(| value | value as $ty).collect()
+                }
+            }
+
+            impl Strategy for RangeInclusive<$ty> {
+                type Value = $ty;
+
+                fn generate(&self, rng: &mut Rng) -> $ty {
+                    assert!(!self.is_empty(), "cannot generate values from an empty range");
+                    let span = (*self.end() as i128 - *self.start() as i128) as u128 + 1;
+                    (*self.start() as i128 + rng.below(span) as i128) as $ty
+                }
+
+                fn shrink(&self, value: &$ty) -> Vec<$ty> {
+                    shrink_towards(*value as i128, *self.start() as i128).into_iter().map(| value | value as $ty).collect()
+                }
+            }
+        )+
+    };
+}
+
+impl_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+macro_rules! impl_wide_int {
+    ($($ty:ty),+) => {
+        $(
+            impl Arbitrary for $ty {
+                fn arbitrary(rng: &mut Rng) -> Self {
+                    if rng.one_in(8) {
+                        let edges = [0, 1, <$ty>::MIN, <$ty>::MAX];
+                        return edges[rng.below(edges.len() as u128) as usize];
+                    }
+
+                    (((rng.next_u64() as u128) << 64) | rng.next_u64() as u128) as $ty
+                }
+
+                fn shrink(&self) -> Vec<Self> {
+                    let mut candidates: Vec<Self> = Vec::new();
+                    let mut value = *self;
+
+                    while value != 0 {
+                        value /= 2;
+                        candidates.push(value);
+                    }
+
+                    candidates.reverse();
+                    candidates
+                }
+            }
+        )+
+    };
+}
+
+impl_wide_int!(u128, i128);
+
+macro_rules! impl_float {
+    ($($ty:ident),+) => {
+        $(
+            impl Arbitrary for $ty {
+                fn arbitrary(rng: &mut Rng) -> Self {
+                    if rng.one_in(8) {
+                        let edges = [0.0, 1.0, -1.0, $ty::EPSILON, $ty::MIN_POSITIVE, $ty::MIN, $ty::MAX];
+                        return edges[rng.below(edges.len() as u128) as usize];
+                    }
+
+                    // Spread magnitudes across 1e-6..1e6, rather than clustering within 0..1
+                    let sign = if rng.one_in(2) { -1.0 } else { 1.0 };
+                    let exponent = rng.below(13) as i32 - 6;
+                    (sign * rng.unit() * 10f64.powi(exponent)) as $ty
+                }
+
+                fn shrink(&self) -> Vec<Self> {
+                    let candidates = [0.0, self.trunc(), *self / 2.0];
+                    let mut out: Vec<Self> = Vec::new();
+
+                    for candidate in candidates {
+                        if candidate.abs() < self.abs() && !out.contains(&candidate) {
+                            out.push(candidate);
+                        }
+                    }
+
+                    out
+                }
+            }
+        )+
+    };
+}
+
+impl_float!(f32, f64);
+
+impl Arbitrary for bool {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        rng.one_in(2)
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        match self {
+            true => vec![false],
+            false => Vec::new()
+        }
+    }
+}
+
+impl Arbitrary for char {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        // Mostly printable ASCII, occasionally any scalar value
+        if rng.one_in(4) {
+            loop {
+                if let Some(c) = char::from_u32(rng.below(0x11_0000) as u32) {
+                    return c;
+                }
+            }
+        }
+
+        (0x20 + rng.below(0x5F) as u8) as char
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        match *self > 'a' {
+            true => vec!['a'],
+            false => Vec::new()
+        }
+    }
+}
+
+impl<T: Arbitrary + Clone> Arbitrary for Vec<T> {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        (0..rng.below(MAX_LEN + 1)).map(| _ | T::arbitrary(rng)).collect()
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+
+        // Structural shrinks first: empty, halves, then each element removed
+        let mut candidates: Vec<Self> = vec![Vec::new()];
+        if self.len() > 1 {
+            candidates.push(self[..self.len() / 2].to_vec());
+            candidates.push(self[self.len() / 2..].to_vec());
+        }
+
+        for i in 0..self.len() {
+            let mut removed = self.clone();
+            removed.remove(i);
+            candidates.push(removed);
+        }
+
+        for (i, element) in self.iter().enumerate() {
+            for shrunk in element.shrink() {
+                let mut replaced = self.clone();
+                replaced[i] = shrunk;
+                candidates.push(replaced);
+            }
+        }
+
+        candidates
+    }
+}
+
+impl Arbitrary for String {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        Vec::<char>::arbitrary(rng).into_iter().collect()
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        self.chars().collect::<Vec<char>>().shrink().into_iter()
+            .map(| chars | chars.into_iter().collect())
+            .collect()
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Option<T> {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        match rng.one_in(4) {
+            true => None,
+            false => Some(T::arbitrary(rng))
+        }
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        match self {
+            Some(value) => core::iter::once(None).chain(value.shrink().into_iter().map(Some)).collect(),
+            None => Vec::new()
+        }
+    }
+}
+
+macro_rules! impl_tuple {
+    ($(($($name:ident $index:tt),+)),+) => {
+        $(
+            impl<$($name: Arbitrary + Clone),+> Arbitrary for ($($name,)+) {
+                fn arbitrary(rng: &mut Rng) -> Self {
+                    ($($name::arbitrary(rng),)+)
+                }
+
+                fn shrink(&self) -> Vec<Self> {
+                    let mut candidates: Vec<Self> = Vec::new();
+                    $(
+                        for shrunk in self.$index.shrink() {
+                            let mut candidate = self.clone();
+                            candidate.$index = shrunk;
+                            candidates.push(candidate);
+                        }
+                    )+
+
+                    candidates
+                }
+            }
+
+            impl<$($name: Strategy),+> Strategy for ($($name,)+) where $($name::Value: Clone),+ {
+                type Value = ($($name::Value,)+);
+
+                fn generate(&self, rng: &mut Rng) -> Self::Value {
+                    ($(self.$index.generate(rng),)+)
+                }
+
+                fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+                    let mut candidates: Vec<Self::Value> = Vec::new();
+                    $(
+                        for shrunk in self.$index.shrink(&value.$index) {
+                            let mut candidate = value.clone();
+                            candidate.$index = shrunk;
+                            candidates.push(candidate);
+                        }
+                    )+
+
+                    candidates
+                }
+            }
+        )+
+    };
+}
+
+impl_tuple!(
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges_generate_within_bounds() {
+        let mut rng = Rng::new(0);
+
+        for _ in 0..256 {
+            assert!((10..20u8).contains(&(10..20u8).generate(&mut rng)));
+            assert!((-5..=5i32).contains(&(-5..=5i32).generate(&mut rng)));
+        }
+    }
+
+    #[test]
+    fn ints_shrink_towards_zero() {
+        assert_eq!(100u8.shrink(), [0, 50, 75, 88, 94, 97, 99]);
+        assert_eq!((-8i32).shrink(), [0, -4, -6, -7]);
+        assert!(0u32.shrink().is_empty());
+    }
+
+    #[test]
+    fn ranges_shrink_towards_start() {
+        assert_eq!((10..20u8).shrink(&14), [10, 12, 13]);
+    }
+
+    #[test]
+    fn vecs_shrink_structurally_first() {
+        let candidates = vec![3u8, 4].shrink();
+
+        assert_eq!(candidates[..5], [vec![], vec![3], vec![4], vec![4], vec![3]]);
+    }
+
+    #[test]
+    fn options_shrink_to_none_first() {
+        assert_eq!(Some(2u8).shrink(), [None, Some(0), Some(1)]);
+    }
+
+    #[test]
+    fn tuples_shrink_each_component() {
+        let strategy = (0..10u8, just("fixed"));
+
+        assert_eq!(strategy.shrink(&(2, "fixed")), [(0, "fixed"), (1, "fixed")]);
+    }
+
+    #[test]
+    fn floats_shrink_towards_zero() {
+        assert_eq!(2.5f64.shrink(), [0.0, 2.0, 1.25]);
+        assert!(0.0f64.shrink().is_empty());
+    }
+
+    #[test]
+    fn generation_is_deterministic_per_seed() {
+        let strategy = any::<(Vec<u16>, Option<String>, f64)>();
+        let (left, right) = (strategy.generate(&mut Rng::new(1)), strategy.generate(&mut Rng::new(1)));
+
+        assert_eq!(format!("{:?}", left), format!("{:?}", right));
+    }
+}
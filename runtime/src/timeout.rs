@@ -0,0 +1,257 @@
+//! Drives `#[test_case(timeout = "...")]` & `#[test_suite(timeout = "...")]`.
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{
+    Context, Poll, Waker
+};
+use core::time::Duration;
+use std::panic::{
+    self, AssertUnwindSafe
+};
+use std::sync::{
+    Arc, Condvar, Mutex,
+    mpsc::{
+        self, RecvTimeoutError
+    }
+};
+use std::thread;
+use std::time::Instant;
+use crate::property::Outcome;
+
+/// Runs `body` on a watchdog thread, failing `test` should it not complete within `timeout`.
+///
+/// A body which never completes (i.e. is deadlocked) can't be cancelled - its thread is
+/// abandoned, so the remaining tests may still run. It runs on alongside such though, having
+/// released its `serial` lock (if any) as the test failed - so may interfere with the tests of
+/// its group. Only the failure of its output (if any) is sent back, so outputs need not be
+/// `Send`, i.e. `Result<(), Box<dyn Error>>` - a passing output is rebuilt as such.
+///
+/// # Panics
+///
+/// If `body` panics or fails, or `timeout` elapses.
+pub fn run<F, R>(test: &'static str, timeout: Duration, body: F) -> R where
+    F: FnOnce() -> R + Send + 'static,
+    R: Outcome
+{
+    let (sender, receiver) = mpsc::channel();
+    let started: Instant = Instant::now();
+
+    // Named after the test thread, so panics within are attributed to the test
+    let mut builder = thread::Builder::new();
+    if let Some(name) = thread::current().name() {
+        builder = builder.name(String::from(name));
+    }
+
+    builder.spawn(move || {
+        let _ = sender.send(panic::catch_unwind(AssertUnwindSafe(| | body().failure())));
+    }).expect("failed to spawn watchdog thread");
+
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok(None)) => R::success(),
+        Ok(Ok(Some(failure))) => panic!("test `{}` failed: {}", test, failure),
+        Ok(Err(payload)) => panic::resume_unwind(payload),
+        Err(RecvTimeoutError::Timeout) => timed_out(test, timeout, started),
+        Err(RecvTimeoutError::Disconnected) => panic!("test `{}` exited without completing", test)
+    }
+}
+
+/// Fails `test` should `future` not complete within `timeout`, dropping (and so cancelling) it.
+///
+/// Agnostic of the async runtime - the deadline is tracked by a timer thread, which exits
+/// upon the deadline elapsing, or the future completing (or being dropped) before then.
+pub struct Timeout<F> {
+    test: &'static str,
+    timeout: Duration,
+    started: Option<Instant>,
+    timer: Arc<Timer>,
+    future: Pin<Box<F>>
+}
+
+#[derive(Default)]
+struct Timer {
+    state: Mutex<TimerState>,
+    completed: Condvar
+}
+
+#[derive(Default)]
+struct TimerState {
+    is_expired: bool,
+    is_completed: bool,
+    waker: Option<Waker>
+}
+
+impl Timer {
+    fn state(&self) -> std::sync::MutexGuard<'_, TimerState> {
+        self.state.lock().unwrap_or_else(| e | e.into_inner())
+    }
+}
+
+impl<F: Future> Timeout<F> {
+    pub fn new(test: &'static str, timeout: Duration, future: F) -> Self {
+        Self {
+            test, timeout,
+            started: None,
+            timer: Arc::default(),
+            future: Box::pin(future)
+        }
+    }
+
+    fn start_timer(&self) {
+        let (timeout, timer) = (self.timeout, self.timer.clone());
+
+        thread::spawn(move || {
+            let (mut state, _) = timer.completed
+                .wait_timeout_while(timer.state(), timeout, | state | !state.is_completed)
+                .unwrap_or_else(| e | e.into_inner());
+            if state.is_completed {
+                return;
+            }
+
+            state.is_expired = true;
+            if let Some(waker) = state.waker.take() {
+                drop(state);
+                waker.wake();
+            }
+        });
+    }
+}
+
+impl<F> Drop for Timeout<F> {
+    fn drop(&mut self) {
+        self.timer.state().is_completed = true;
+        self.timer.completed.notify_all();
+    }
+}
+
+impl<F: Future> Future for Timeout<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.timer.state().waker = Some(cx.waker().clone());
+        if self.started.is_none() {
+            self.started = Some(Instant::now());
+            self.start_timer();
+        }
+
+        if let Poll::Ready(output) = self.future.as_mut().poll(cx) {
+            return Poll::Ready(output);
+        }
+
+        let is_expired: bool = self.timer.state().is_expired;
+        match (is_expired, self.started) {
+            (true, Some(started)) => timed_out(self.test, self.timeout, started),
+            _ => Poll::Pending
+        }
+    }
+}
+
+fn timed_out(test: &str, timeout: Duration, started: Instant) -> ! {
+    panic!("test `{}` timed out after {:?} (timeout: {:?})", test, started.elapsed(), timeout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::task::Wake;
+
+    struct ThreadWaker(thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker: Waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut context = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park()
+            }
+        }
+    }
+
+    // Never completes, as nothing wakes it
+    struct Pending;
+
+    impl Future for Pending {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn run_returns_output_within_timeout() {
+        // Not `Send`, so only its failure is sent back by the watchdog thread
+        let output: Result<(), Box<dyn std::error::Error>> = run("quick", Duration::from_secs(5), || Ok(()));
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    fn run_propagates_panics() {
+        let payload = panic::catch_unwind(|| {
+            run::<_, ()>("panics", Duration::from_secs(5), || panic!("inner"))
+        }).unwrap_err();
+
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"inner"));
+    }
+
+    #[test]
+    fn run_fails_upon_timeout() {
+        let payload = panic::catch_unwind(|| {
+            run("hangs", Duration::from_millis(10), || thread::sleep(Duration::from_secs(1)))
+        }).unwrap_err();
+
+        let message: &String = payload.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("test `hangs` timed out after "), "{}", message);
+    }
+
+    #[test]
+    fn run_fails_upon_failing_output() {
+        let payload = panic::catch_unwind(|| {
+            run("fails", Duration::from_secs(5), || -> Result<(), Box<dyn std::error::Error>> {
+                Err("invalid".into())
+            })
+        }).unwrap_err();
+
+        let message: &String = payload.downcast_ref::<String>().unwrap();
+        assert_eq!(message, "test `fails` failed: Err(\"invalid\")");
+    }
+
+    #[test]
+    fn timeout_returns_output_within_timeout() {
+        assert_eq!(block_on(Timeout::new("quick", Duration::from_secs(5), async { 1 + 1 })), 2);
+    }
+
+    #[test]
+    fn timeout_stops_timer_upon_completion() {
+        let timeout = Timeout::new("quick", Duration::from_secs(60), async { 1 + 1 });
+        let timer: Arc<Timer> = timeout.timer.clone();
+        assert_eq!(block_on(timeout), 2);
+
+        // Released by the timer thread as it exits, long before the deadline
+        let started: Instant = Instant::now();
+        while Arc::strong_count(&timer) > 1 {
+            assert!(started.elapsed() < Duration::from_secs(5), "timer thread outlived its future");
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn timeout_cancels_future_upon_timeout() {
+        let payload = panic::catch_unwind(|| {
+            block_on(Timeout::new("hangs", Duration::from_millis(10), Pending))
+        }).unwrap_err();
+
+        let message: &String = payload.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("test `hangs` timed out after "), "{}", message);
+    }
+}
@@ -0,0 +1,74 @@
+//! Drives `#[test_case(xfail("..."))]`.
+
+use std::{
+    io::Write,
+    panic::{
+        self, AssertUnwindSafe
+    }
+};
+
+use crate::{
+    failure::panic_message,
+    property::Outcome
+};
+
+/// Runs `body`, which is expected to fail - by panicking, or returning `Err(...)`. The test
+/// passes should it fail, with the failure reported so it may be checked it's still the
+/// same bug.
+///
+/// # Panics
+///
+/// If `body` unexpectedly passes.
+pub fn run<F, R>(test: &str, reason: &str, body: F) -> R where
+    F: FnOnce() -> R,
+    R: Outcome
+{
+    let failure: Option<String> = match panic::catch_unwind(AssertUnwindSafe(body)) {
+        Ok(output) => output.failure(),
+        Err(payload) => Some(panic_message(payload.as_ref()))
+    };
+
+    match failure {
+        Some(failure) => {
+            // As with skips, written to stderr directly so it isn't captured by libtest
+            let _ = writeln!(std::io::stderr(), "{}", expected(test, reason, &failure));
+            R::success()
+        },
+        None => panic!("test `{}` unexpectedly passed, remove xfail ({})", test, reason)
+    }
+}
+
+fn expected(test: &str, reason: &str, failure: &str) -> String {
+    format!("test `{}` failed as expected ({}): {}", test, reason, failure)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_passes_on_panic() {
+        run::<_, ()>("known_bug", "issue 142", || panic!("still broken"));
+    }
+
+    #[test]
+    fn run_passes_on_error() {
+        let output: Result<(), &str> = run("known_bug", "issue 142", || Err("still broken"));
+
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "test `fixed_bug` unexpectedly passed, remove xfail (issue 142)")]
+    fn run_fails_on_pass() {
+        run("fixed_bug", "issue 142", || {});
+    }
+
+    #[test]
+    fn expected_includes_reason_and_failure() {
+        assert_eq!(
+            expected("known_bug", "issue 142", "still broken"),
+            "test `known_bug` failed as expected (issue 142): still broken"
+        );
+    }
+}
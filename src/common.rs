@@ -1,12 +1,16 @@
+use quote::ToTokens;
+
+use core::mem::take;
 use proc_macro2::{
-    Delimiter,
+    Delimiter, Group, Spacing,
     TokenStream, TokenTree
 };
 use syn::{
-    Attribute, Result,
+    Attribute, Ident, Result,
     parse::{
         ParseStream, Parse
-    }
+    },
+    buffer::Cursor
 };
 
 use crate::common::macros::error_spanned;
@@ -65,6 +69,93 @@ pub fn parse_next_tt(input: ParseStream) -> Result<TokenTree> {
     })
 }
 
+pub fn recursive_descent_replace(input: &mut Cursor, pattern: &Ident, substitute: &TokenStream) -> TokenStream {
+    let mut out = TokenStream::new();
+    while let Some((tt, next)) = input.token_tree() {
+        match tt {
+            TokenTree::Group(item) => {
+                let (mut start, _, _) = input.group(item.delimiter()).unwrap();
+
+                let mut group = Group::new(
+                    item.delimiter(),
+                    recursive_descent_replace(&mut start, pattern, substitute)
+                );
+                group.set_span(item.span());
+                group.to_tokens(&mut out);
+            },
+            TokenTree::Ident(item) if item.eq(pattern) => {
+                substitute.to_tokens(&mut out);
+            },
+            _ => {
+                tt.to_tokens(&mut out);
+            }
+        }
+
+        *input = next;
+    }
+
+    out
+}
+
+pub fn tokens_contain_ident(tokens: TokenStream, pattern: &Ident) -> bool {
+    tokens.into_iter().any(| tt | match tt {
+        TokenTree::Group(group) => tokens_contain_ident(group.stream(), pattern),
+        TokenTree::Ident(ident) => ident.eq(pattern),
+        _ => false
+    })
+}
+
+pub fn tokens_to_snake_case(tokens: TokenStream) -> String {
+    let mut fragments: Vec<String> = Vec::new();
+    collect_snake_case_fragments(tokens, &mut fragments);
+
+    fragments.join("_")
+}
+
+fn collect_snake_case_fragments(tokens: TokenStream, fragments: &mut Vec<String>) {
+    for tt in tokens {
+        let text: String = match tt {
+            TokenTree::Group(group) => {
+                collect_snake_case_fragments(group.stream(), fragments);
+                continue;
+            },
+            // Keep negative literals distinguishable from their positive counterparts
+            TokenTree::Punct(punct) if punct.as_char() == '-' && punct.spacing() == Spacing::Alone => {
+                String::from("neg")
+            },
+            TokenTree::Punct(_) => continue,
+            TokenTree::Ident(ident) => ident.to_string().trim_start_matches("r#").to_owned(),
+            TokenTree::Literal(literal) => literal.to_string()
+        };
+
+        let chars: Vec<char> = text.chars().collect();
+        let mut fragment = String::new();
+        for (i, c) in chars.iter().enumerate() {
+            if !c.is_ascii_alphanumeric() {
+                if !fragment.is_empty() {
+                    fragments.push(take(&mut fragment));
+                }
+                continue;
+            }
+
+            // CamelCase -> camel_case, keeping acronyms (i.e `HTTPServer` -> `http_server`) intact
+            let is_word_boundary = i > 0 && c.is_ascii_uppercase() && (
+                chars[i - 1].is_ascii_lowercase() || chars[i - 1].is_ascii_digit() ||
+                (chars[i - 1].is_ascii_uppercase() && chars.get(i + 1).is_some_and(char::is_ascii_lowercase))
+            );
+            if is_word_boundary && !fragment.is_empty() {
+                fragments.push(take(&mut fragment));
+            }
+
+            fragment.push(c.to_ascii_lowercase());
+        }
+
+        if !fragment.is_empty() {
+            fragments.push(fragment);
+        }
+    }
+}
+
 #[macro_use]
 pub(crate) mod macros {
     macro_rules! error_spanned {
@@ -483,6 +574,162 @@ pub(crate) mod tests {
         }
     }
 
+    mod recursive_descent_replace {
+        use super::*;
+
+        use syn::{
+            parse_quote,
+            buffer::TokenBuffer
+        };
+
+        #[test]
+        fn works_within_nested_parenthesis() {
+            let target = TokenBuffer::new2(quote!{
+                let val: usize = foo(r#replace, bar(r#replace));
+            });
+
+            let new = recursive_descent_replace(
+                &mut target.begin(),
+                &parse_quote!(r#replace),
+                &quote!(123)
+            );
+
+            assert_eq_tokens!(new, quote!{
+                let val: usize = foo(123, bar(123));
+            });
+        }
+
+        #[test]
+        fn works_within_nested_braces() {
+            let target = TokenBuffer::new2(quote!{
+                let val: Matrix = if true {
+                    Matrix::M2 { x: r#replace, y: 1 }
+                } else {
+                    Matrix::M2 { x: 1, y: r#replace }
+                }
+            });
+
+            let new = recursive_descent_replace(
+                &mut target.begin(),
+                &parse_quote!(r#replace),
+                &quote!(123)
+            );
+
+            assert_eq_tokens!(new, quote!{
+                let val: Matrix = if true {
+                    Matrix::M2 { x: 123, y: 1 }
+                } else {
+                    Matrix::M2 { x: 1, y: 123 }
+                }
+            });
+        }
+
+        #[test]
+        fn works_within_nested_brackets() {
+            let target = TokenBuffer::new2(quote!{
+                let tiles: [Chunk<[Tile; r#replace]>; r#replace];
+            });
+
+            let new = recursive_descent_replace(
+                &mut target.begin(),
+                &parse_quote!(r#replace),
+                &quote!(64)
+            );
+
+            assert_eq_tokens!(new, quote!{
+                let tiles: [Chunk<[Tile; 64]>; 64];
+            });
+        }
+
+        #[test]
+        fn works_with_empty_substitution_tokens() {
+            let target = TokenBuffer::new2(quote!());
+            let new = recursive_descent_replace(
+                &mut target.begin(),
+                &parse_quote!(r#replace),
+                &quote!(64)
+            );
+
+            assert_eq_tokens!(new, quote!());
+        }
+
+        #[test]
+        fn result_is_unchanged_when_no_matches_found() {
+            let target = TokenBuffer::new2(quote!{
+                let a: usize = usize::MAX;
+            });
+
+            let new = recursive_descent_replace(
+                &mut target.begin(),
+                &parse_quote!(r#replace),
+                &quote!(64)
+            );
+
+            assert_eq_tokens!(new, quote!{
+                let a: usize = usize::MAX;
+            });
+        }
+    }
+
+    mod tokens_contain_ident {
+        use super::*;
+
+        #[test]
+        fn finds_ident_within_nested_groups() {
+            assert!(tokens_contain_ident(
+                quote!(Vec<(u8, [T; 4])>),
+                &Ident::new("T", Span::call_site())
+            ));
+        }
+
+        #[test]
+        fn does_not_match_partial_idents() {
+            assert!(!tokens_contain_ident(
+                quote!(Type<TT>),
+                &Ident::new("T", Span::call_site())
+            ));
+        }
+    }
+
+    mod tokens_to_snake_case {
+        use super::*;
+
+        #[test]
+        fn converts_camel_case_idents() {
+            assert_eq!(tokens_to_snake_case(quote!(LockFreeQueue)), "lock_free_queue");
+        }
+
+        #[test]
+        fn preserves_acronyms() {
+            assert_eq!(tokens_to_snake_case(quote!(HTTPServer)), "http_server");
+        }
+
+        #[test]
+        fn joins_paths_and_generics() {
+            assert_eq!(tokens_to_snake_case(quote!(std::vec::Vec<u8>)), "std_vec_vec_u8");
+        }
+
+        #[test]
+        fn strips_raw_prefixes() {
+            assert_eq!(tokens_to_snake_case(quote!(r#type)), "type");
+        }
+
+        #[test]
+        fn sanitizes_literals() {
+            assert_eq!(tokens_to_snake_case(quote!("Hello, World!")), "hello_world");
+        }
+
+        #[test]
+        fn distinguishes_negative_literals() {
+            assert_eq!(tokens_to_snake_case(quote!(-1)), "neg_1");
+        }
+
+        #[test]
+        fn returns_empty_string_on_empty_input() {
+            assert_eq!(tokens_to_snake_case(quote!()), "");
+        }
+    }
+
     pub(crate) mod shim {
         macro_rules! impl_parse_shim {
             ($for_type:ty, $use_fn:path) => {
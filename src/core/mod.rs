@@ -16,8 +16,9 @@ pub use test_case::{
     TestCase, render_test_case
 };
 pub use test_suite::{
-    TestSuite, render_test_suite
+    TestSuite, TestSuiteParams, render_test_suite
 };
+pub use params::context::render_current_test;
 
 type Mutators<T> = BTreeSet<T>;
 
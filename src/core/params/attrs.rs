@@ -0,0 +1,106 @@
+use syn::{
+    Attribute, ItemFn, Token, Result,
+    parse::{
+        Parse, ParseStream
+    }
+};
+use crate::{
+    common::macros::error_spanned,
+    params::{
+        Mutate, macros::*
+    }
+};
+
+#[derive(Clone)]
+pub(crate) struct ParamAttrs(pub Vec<Attribute>);
+
+impl Parse for ParamAttrs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut attrs: Vec<Attribute> = Vec::new();
+        loop {
+            let parsed: Vec<Attribute> = input.call(Attribute::parse_outer)?;
+            if parsed.is_empty() {
+                return Err(error_spanned!("attrs(): expected attributes, i.e. `#[allow(...)]`", &input.span()));
+            }
+
+            attrs.extend(parsed);
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+
+            if input.is_empty() {
+                return Ok(Self(attrs));
+            }
+        }
+    }
+}
+
+impl Mutate for ParamAttrs {
+    type Item = ItemFn;
+
+    // Appended after the test attribute, so attribute macros wrapping the test (i.e.
+    // `#[serial_test::serial]`) see it - and the body generated by sith, expanding after such
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        target.attrs.extend(self.0.iter().cloned());
+
+        Ok(())
+    }
+}
+
+impl_unique!(ParamAttrs);
+impl_param!(ParamAttrs, iterable(0));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::{
+        quote, ToTokens
+    };
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_accepts_attributes() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamAttrs>(quote!(#[track_caller], #[allow(clippy::all)])),
+            Ok(quote!(#[track_caller] #[allow(clippy::all)]))
+        );
+        assert_eq_parsed!(
+            syn::parse2::<ParamAttrs>(quote!(#[doc = "docs"])),
+            Ok(quote!(#[doc = "docs"]))
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_on_non_attributes() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamAttrs>(quote!()),
+            Err(error_spanned!("attrs(): expected attributes, i.e. `#[allow(...)]`"))
+        );
+        assert_eq_parsed!(
+            syn::parse2::<ParamAttrs>(quote!(#[track_caller], allow(dead_code))),
+            Err(error_spanned!("attrs(): expected attributes, i.e. `#[allow(...)]`"))
+        );
+    }
+
+    #[test]
+    fn mutate_appends_attributes_in_order() {
+        let mut target: ItemFn = parse_quote!{
+            #[test]
+            fn test() {}
+        };
+
+        let param: ParamAttrs = parse_quote!(#[serial], #[allow(unused)]);
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            #[test]
+            #[serial]
+            #[allow(unused)]
+            fn test() {}
+        });
+    }
+}
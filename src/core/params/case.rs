@@ -0,0 +1,151 @@
+use proc_macro2::{
+    Span, TokenStream
+};
+use syn::{
+    Ident, ItemFn, Block, Result,
+    ext::IdentExt
+};
+use crate::params::Mutate;
+
+// An input as reported upon failure - bound inputs additionally report their value
+#[derive(Clone, Debug)]
+pub(crate) struct CaseInput {
+    pub name: String,
+    pub source: TokenStream,
+    pub binding: Option<Ident>
+}
+
+// Reports the test case, its inputs and the location of its attribute, should the test panic
+#[derive(Clone, Debug)]
+pub(crate) struct ParamCase {
+    span: Span,
+    inputs: Vec<CaseInput>
+}
+
+impl ParamCase {
+    pub fn new(span: Span, inputs: Vec<CaseInput>) -> Self {
+        Self { span, inputs }
+    }
+
+    // Records each input's `Debug` value once bound - must precede the binding of
+    // inputs, as such are prepended ahead of the recording
+    pub fn record_inputs(&self, target: &mut Block) {
+        let stmts = self.inputs.iter().filter_map(| input | {
+            let (name, binding) = (&input.name, input.binding.as_ref()?);
+            Some(syn::parse_quote!{
+                __sith_case.bind(#name, {
+                    use ::sith_runtime::failure::{DescribeDebug as _, DescribeOpaque as _};
+                    (&::sith_runtime::failure::Described(&#binding)).describe()
+                });
+            })
+        }).collect::<Vec<syn::Stmt>>();
+
+        target.stmts.splice(0..0, stmts);
+    }
+}
+
+// The text of `tokens` as written - where the compiler can't join the spans of multiple tokens
+// (i.e. on stable), such have none, rather than being re-stringified as `vec ! [1, 2]`
+fn source_text(tokens: TokenStream) -> Option<String> {
+    let mut spans = tokens.into_iter().map(| token | token.span());
+    let first: Span = spans.next()?;
+
+    match spans.last() {
+        Some(last) => first.join(last)?.source_text(),
+        None => first.source_text()
+    }
+}
+
+impl Mutate for ParamCase {
+    type Item = ItemFn;
+
+    // Declared ahead of all other statements (bar the seed), so inputs may be recorded as bound
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        let name: String = target.sig.ident.unraw().to_string();
+        let (names, sources) = self.inputs.iter().map(| input | (&input.name, match source_text(input.source.clone()) {
+            Some(source) => quote::quote!(::core::option::Option::Some(#source)),
+            None => quote::quote!(::core::option::Option::None)
+        })).unzip::<_, _, Vec<&String>, Vec<TokenStream>>();
+
+        // Located at the #[test_case] attribute, rather than the function
+        let file = quote::quote_spanned!(self.span=> ::core::file!());
+        let line = quote::quote_spanned!(self.span=> ::core::line!());
+
+        target.block.stmts.insert(0, syn::parse_quote!{
+            let __sith_case = ::sith_runtime::Case::new(#name, #file, #line, &[#((#names, #sources)),*]);
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::{
+        quote, ToTokens
+    };
+    use syn::parse_quote;
+
+    fn case() -> ParamCase {
+        ParamCase::new(Span::call_site(), vec![
+            CaseInput { name: String::from("a"), source: quote!(1 + 1), binding: Some(parse_quote!(a)) },
+            CaseInput { name: String::from("r#type"), source: quote!(u8), binding: None }
+        ])
+    }
+
+    #[test]
+    fn record_inputs_prepends_bound_inputs() {
+        let mut target: Block = parse_quote!({ consume(a); });
+
+        case().record_inputs(&mut target);
+        assert_eq_tokens!(
+            target,
+            quote!({
+                __sith_case.bind("a", {
+                    use ::sith_runtime::failure::{DescribeDebug as _, DescribeOpaque as _};
+                    (&::sith_runtime::failure::Described(&a)).describe()
+                });
+                consume(a);
+            })
+        );
+    }
+
+    #[test]
+    fn source_text_requires_a_source() {
+        // Spans created outside of a macro expansion have no source text
+        assert_eq!(source_text(quote!(1 + 1)), None);
+        assert_eq!(source_text(TokenStream::new()), None);
+    }
+
+    #[test]
+    fn mutate_declares_case_before_body() {
+        let mut target: ItemFn = parse_quote!{
+            fn my_test_two() {
+                body();
+            }
+        };
+
+        assert_eq_mutate!(case(), &mut target, Ok(()));
+        assert_eq_tokens!(
+            target,
+            quote!{
+                fn my_test_two() {
+                    let __sith_case = ::sith_runtime::Case::new(
+                        "my_test_two", ::core::file!(), ::core::line!(),
+                        &[
+                            ("a", ::core::option::Option::None),
+                            ("r#type", ::core::option::Option::None)
+                        ]
+                    );
+                    body();
+                }
+            }
+        );
+    }
+}
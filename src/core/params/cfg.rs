@@ -0,0 +1,141 @@
+use syn::{
+    Attribute, Meta, ItemFn, Token, Result,
+    punctuated::Punctuated,
+    parse::{
+        Parse, ParseStream
+    }
+};
+use crate::{
+    common::attribute_name_to_string,
+    params::{
+        Mutate, macros::*
+    }
+};
+
+#[derive(Clone)]
+pub(crate) struct ParamCfg(pub Meta);
+
+impl Parse for ParamCfg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self(input.parse::<Meta>()?))
+    }
+}
+
+impl ParamCfg {
+    pub const CFG_ATTR_IDENT: &'static str = "cfg_attr";
+
+    // Holds should both predicates hold
+    pub fn and(&self, other: &ParamCfg) -> ParamCfg {
+        let (left, right) = (&self.0, &other.0);
+        ParamCfg(syn::parse_quote!(all(#left, #right)))
+    }
+
+    // Holds should any of the predicates hold
+    pub fn any<'a>(predicates: impl IntoIterator<Item = &'a ParamCfg>) -> ParamCfg {
+        let predicates = predicates.into_iter().map(| predicate | &predicate.0);
+        ParamCfg(syn::parse_quote!(any(#(#predicates),*)))
+    }
+
+    // Wraps `attrs` as `#[cfg_attr(predicate, attrs...)]`
+    pub fn cfg_attr(&self, attrs: &[Meta]) -> Attribute {
+        let predicate = &self.0;
+        syn::parse_quote!(#[cfg_attr(#predicate, #(#attrs),*)])
+    }
+}
+
+// Splits `#[cfg_attr(predicate, attrs...)]` into its predicate, the attributes named `name`
+// within, and those remaining - should it contain any such attribute
+pub(crate) fn split_cfg_attr(attr: &Attribute, name: &str) -> Option<(ParamCfg, Vec<Meta>, Vec<Meta>)> {
+    if attribute_name_to_string(attr) != ParamCfg::CFG_ATTR_IDENT {
+        return None;
+    }
+
+    let (predicate, attrs) = attr.parse_args_with(| input: ParseStream | {
+        let predicate = input.parse::<ParamCfg>()?;
+        input.parse::<Token![,]>()?;
+        Ok((predicate, Punctuated::<Meta, Token![,]>::parse_terminated(input)?))
+    }).ok()?;
+
+    let (matched, rest): (Vec<Meta>, Vec<Meta>) = attrs.into_iter().partition(| meta | {
+        meta.path().segments.last().is_some_and(| segment | segment.ident == name)
+    });
+
+    if matched.is_empty() {
+        return None;
+    }
+
+    Some((predicate, matched, rest))
+}
+
+impl Mutate for ParamCfg {
+    type Item = ItemFn;
+
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        let predicate = &self.0;
+        target.attrs.push(syn::parse_quote!(#[cfg(#predicate)]));
+
+        Ok(())
+    }
+}
+
+impl_unique!(ParamCfg);
+impl_param!(ParamCfg, 0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::{
+        quote, ToTokens
+    };
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_accepts_predicates() {
+        assert_eq_parsed!(syn::parse2::<ParamCfg>(quote!(unix)), Ok(quote!(unix)));
+        assert_eq_parsed!(syn::parse2::<ParamCfg>(quote!(feature = "simd")), Ok(quote!(feature = "simd")));
+        assert_eq_parsed!(syn::parse2::<ParamCfg>(quote!(all(unix, test))), Ok(quote!(all(unix, test))));
+    }
+
+    #[test]
+    fn mutate_attaches_cfg() {
+        let mut target: ItemFn = parse_quote!(fn test() {});
+
+        let param: ParamCfg = parse_quote!(feature = "simd");
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            #[cfg(feature = "simd")]
+            fn test() {}
+        });
+    }
+
+    #[test]
+    fn and_combines_predicates() {
+        let left: ParamCfg = parse_quote!(unix);
+        assert_eq_tokens!(left.and(&parse_quote!(test)), quote!(all(unix, test)));
+    }
+
+    #[test]
+    fn split_cfg_attr_plucks_named_attributes() {
+        let attr: Attribute = parse_quote!(#[cfg_attr(unix, test_case(one), allow(dead_code), sith::test_case)]);
+
+        let Some((predicate, matched, rest)) = split_cfg_attr(&attr, "test_case") else {
+            panic!("Failed to split: {}", attr.to_token_stream());
+        };
+
+        assert_eq_tokens!(predicate, quote!(unix));
+        assert_eq_tokens!(matched[0], quote!(test_case(one)));
+        assert_eq_tokens!(matched[1], quote!(sith::test_case));
+        assert_eq_tokens!(predicate.cfg_attr(&rest), quote!(#[cfg_attr(unix, allow(dead_code))]));
+    }
+
+    #[test]
+    fn split_cfg_attr_ignores_other_attributes() {
+        assert!(split_cfg_attr(&parse_quote!(#[cfg_attr(unix, allow(dead_code))]), "test_case").is_none());
+        assert!(split_cfg_attr(&parse_quote!(#[test_case(one)]), "test_case").is_none());
+    }
+}
@@ -0,0 +1,235 @@
+use proc_macro2::{
+    Group, TokenStream, TokenTree
+};
+use quote::{
+    quote, ToTokens
+};
+use syn::{
+    Ident, ItemFn, Result,
+    ext::IdentExt,
+    parse::{
+        Parse, ParseStream
+    }
+};
+use crate::{
+    common::macros::error_spanned,
+    params::{
+        Mutate, macros::*,
+        case::CaseInput,
+        tags::ParamTags
+    }
+};
+
+// The context of the case given by `current_test!()`, forwarded by a #[test_suite] as its name
+#[derive(Clone, Default)]
+pub(crate) struct ParamContext {
+    pub suite: Option<Ident>,
+    pub case: Option<String>,
+    pub index: usize,
+    pub inputs: Vec<CaseInput>,
+    pub tags: ParamTags
+}
+
+impl Parse for ParamContext {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            suite: Some(input.parse::<Ident>()?),
+            ..Self::default()
+        })
+    }
+}
+
+impl ParamContext {
+    pub const IDENT: &'static str = "suite";
+    pub const MACRO_IDENT: &'static str = "current_test";
+    pub const BINDING_IDENT: &'static str = "__sith_context";
+
+    // Passes the binding to each `current_test!()` invoked within `tokens` - nested macros and
+    // closures included - returning whether any were. Those not given it weren't invoked within
+    // a test, so are reported by `render_current_test`
+    fn bind_invocations(tokens: TokenStream, binding: &Ident) -> (TokenStream, bool) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        let mut is_invoked: bool = false;
+
+        let bound = tokens.iter().enumerate().map(| (i, token) | match token {
+            TokenTree::Group(group) => {
+                let is_invocation: bool = group.stream().is_empty() && i >= 2 && matches!(
+                    (&tokens[i - 2], &tokens[i - 1]),
+                    (TokenTree::Ident(ident), TokenTree::Punct(punct)) if ident == Self::MACRO_IDENT && punct.as_char() == '!'
+                );
+
+                let stream: TokenStream = match is_invocation {
+                    true => binding.to_token_stream(),
+                    false => {
+                        let (stream, is_nested_invoked) = Self::bind_invocations(group.stream(), binding);
+                        is_invoked |= is_nested_invoked;
+                        stream
+                    }
+                };
+                is_invoked |= is_invocation;
+
+                let mut bound = Group::new(group.delimiter(), stream);
+                bound.set_span(group.span());
+                TokenTree::Group(bound)
+            },
+            token => token.clone()
+        }).collect();
+
+        (bound, is_invoked)
+    }
+}
+
+impl Mutate for ParamContext {
+    type Item = ItemFn;
+
+    // Bound only should the test - or the setup/teardown spliced into it - refer to the context,
+    // so must apply after such
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        let binding = Ident::new(Self::BINDING_IDENT, proc_macro2::Span::call_site());
+        let (block, is_invoked) = Self::bind_invocations(target.block.to_token_stream(), &binding);
+        if !is_invoked {
+            return Ok(());
+        }
+        *target.block = syn::parse2(block)?;
+
+        let name: String = target.sig.ident.unraw().to_string();
+        let suite = match &self.suite {
+            Some(suite) => {
+                let suite: String = suite.unraw().to_string();
+                quote!(::core::option::Option::Some(#suite))
+            },
+            None => quote!(::core::option::Option::None)
+        };
+        let case = match &self.case {
+            Some(case) => quote!(::core::option::Option::Some(#case)),
+            None => quote!(::core::option::Option::None)
+        };
+        let index: usize = self.index;
+        let (names, sources) = self.inputs.iter()
+            .map(| input | (&input.name, input.source.to_string()))
+            .unzip::<_, _, Vec<&String>, Vec<String>>();
+        let tags = self.tags.0.iter().map(| tag | tag.unraw().to_string());
+
+        target.block.stmts.insert(0, syn::parse_quote!{
+            let #binding = ::sith_runtime::TestContext::new(
+                ::core::module_path!(), #name, #suite, #case, #index, &[#((#names, #sources)),*], &[#(#tags),*]
+            );
+        });
+
+        Ok(())
+    }
+}
+
+impl ToTokens for ParamContext {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.suite.to_tokens(tokens);
+    }
+}
+
+impl_unique!(ParamContext);
+
+impl core::fmt::Debug for ParamContext {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ParamContext")
+            .field("suite", &self.suite)
+            .field("case", &self.case)
+            .field("index", &self.index)
+            .field("inputs", &self.inputs)
+            .field("tags", &self.tags)
+            .finish()
+    }
+}
+
+// `current_test!()` - given the context by `ParamContext`, should it be invoked within a test
+pub fn render_current_test(input: TokenStream) -> TokenStream {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    match tokens.as_slice() {
+        [TokenTree::Ident(binding)] if binding == ParamContext::BINDING_IDENT => binding.to_token_stream(),
+        [] => error_spanned!(
+            "current_test!() is only available within a test generated by sith, or the \
+            #[setup]/#[teardown] of its suite - not within functions called by such, nor plain #[test]s"
+        ).to_compile_error(),
+        [token, ..] => error_spanned!("current_test!() takes no arguments", token).to_compile_error()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use syn::parse_quote;
+
+    #[test]
+    fn mutate_binds_context_when_invoked() {
+        let mut target: ItemFn = parse_quote!{
+            fn paint_small_red(color: Color) {
+                let dir = temp_dir(current_test!().name());
+                let log = || println!("{}", current_test!().case());
+            }
+        };
+
+        let context = ParamContext {
+            suite: Some(parse_quote!(paints)),
+            case: Some(String::from("small_red")),
+            index: 2,
+            inputs: vec![CaseInput { name: String::from("color"), source: quote!(Red), binding: None }],
+            tags: parse_quote!(slow)
+        };
+
+        assert_eq_mutate!(context, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            fn paint_small_red(color: Color) {
+                let __sith_context = ::sith_runtime::TestContext::new(
+                    ::core::module_path!(), "paint_small_red", ::core::option::Option::Some("paints"),
+                    ::core::option::Option::Some("small_red"), 2usize, &[("color", "Red")], &["slow"]
+                );
+                let dir = temp_dir(current_test!(__sith_context).name());
+                let log = | | println!("{}", current_test!(__sith_context).case());
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_ignores_tests_not_invoking_context() {
+        let mut target: ItemFn = parse_quote!{
+            fn test() {
+                let current_test = 1;
+            }
+        };
+
+        assert_eq_mutate!(ParamContext::default(), &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            fn test() {
+                let current_test = 1;
+            }
+        });
+    }
+
+    #[test]
+    fn render_current_test_returns_context_when_bound() {
+        assert_eq_tokens!(render_current_test(quote!(__sith_context)), quote!(__sith_context));
+    }
+
+    #[test]
+    fn render_current_test_returns_error_outside_of_tests() {
+        assert_eq_tokens!(
+            render_current_test(quote!()),
+            error_spanned!(
+                "current_test!() is only available within a test generated by sith, or the \
+                #[setup]/#[teardown] of its suite - not within functions called by such, nor plain #[test]s"
+            ).to_compile_error()
+        );
+    }
+
+    #[test]
+    fn render_current_test_returns_error_on_arguments() {
+        assert_eq_tokens!(
+            render_current_test(quote!(ctx)),
+            error_spanned!("current_test!() takes no arguments").to_compile_error()
+        );
+    }
+}
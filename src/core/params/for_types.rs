@@ -0,0 +1,362 @@
+use quote::{
+    format_ident, ToTokens
+};
+use proc_macro2::{
+    TokenStream, Delimiter
+};
+use syn::{
+    Ident, Type, Item, ItemMod, Generics,
+    GenericParam, ImplItem, TraitItem, Stmt,
+    Token, Result,
+    parse::{
+        Parse, ParseStream, Parser
+    },
+    buffer::TokenBuffer
+};
+use crate::{
+    common::{
+        greedy_parse_with_delim,
+        parse_group_with_delim,
+        recursive_descent_replace,
+        tokens_to_snake_case,
+        macros::error_spanned
+    },
+    params::{
+        Mutate, macros::*
+    }
+};
+
+#[derive(Clone)]
+pub(crate) struct TypeAssignment(pub Ident, pub Vec<Type>);
+
+impl Parse for TypeAssignment {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let Result::Ok(ident) = input.parse::<Ident>() else {
+            return Err(error_spanned!("expected type parameter", &input.span()));
+        };
+
+        input.parse::<Token![=]>()?;
+        let types: Vec<Type> = greedy_parse_with_delim::<Type, Token![,]>.parse2(
+            parse_group_with_delim(Delimiter::Bracket, input)?
+        )?;
+
+        if types.is_empty() {
+            return Err(error_spanned!("for_types(): expected at least one type", &ident));
+        }
+
+        Ok(Self(ident, types))
+    }
+}
+
+impl ToTokens for TypeAssignment {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (ident, types) = (&self.0, &self.1);
+        quote::quote!(#ident = [#(#types),*]).to_tokens(tokens);
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct ParamForTypes(pub Vec<TypeAssignment>);
+
+impl Parse for ParamForTypes {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self(
+            greedy_parse_with_delim::<TypeAssignment, Token![,]>(input)?
+        ))
+    }
+}
+
+impl ParamForTypes {
+    // Each assignment multiplies the instances generated thus far,
+    // i.e `T = [A, B], U = [C, D]` produces `A C`, `A D`, `B C` & `B D`
+    pub fn instances(&self) -> Result<Vec<TypeInstance>> {
+        let mut instances: Vec<TypeInstance> = vec![TypeInstance(Vec::new())];
+
+        for TypeAssignment(ident, types) in &self.0 {
+            instances = instances.into_iter().flat_map(| instance | {
+                types.iter().map(move | ty | {
+                    let mut substitutions = instance.0.clone();
+                    substitutions.push((ident.clone(), ty.clone()));
+
+                    TypeInstance(substitutions)
+                })
+            }).collect();
+        }
+
+        let mut names: Vec<String> = Vec::with_capacity(instances.len());
+        for instance in &instances {
+            let name = instance.name();
+            if names.contains(&name) {
+                return Err(error_spanned!(
+                    format!("for_types(): instances resolve to duplicate suite name `{}`", name),
+                    &instance.0.last().map(| (_, ty) | ty)
+                ));
+            }
+
+            names.push(name);
+        }
+
+        Ok(instances)
+    }
+}
+
+impl_unique!(ParamForTypes);
+impl_param!(ParamForTypes, iterable(0));
+
+#[derive(Clone)]
+pub(crate) struct TypeInstance(pub Vec<(Ident, Type)>);
+
+impl TypeInstance {
+    fn name(&self) -> String {
+        self.0.iter()
+            .map(| (_, ty) | tokens_to_snake_case(ty.to_token_stream()))
+            .collect::<Vec<String>>()
+            .join("_")
+    }
+}
+
+impl Mutate for TypeInstance {
+    type Item = ItemMod;
+
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        if target.content.is_none() {
+            return Err(error_spanned!("for_types() can only be applied to inline modules", &target.ident));
+        }
+
+        // Substituted tokens retain the span of their definition within `for_types(...)`,
+        // so compilation errors for an instance are reported against the offending type.
+        // Items declaring a generic parameter of the same name are masked from substitution
+        let mut tokens: TokenStream = target.to_token_stream();
+        for (ident, ty) in &self.0 {
+            let mask: Ident = format_ident!("__sith_shadowed_{}", ident, span = ident.span());
+            let mut masked: ItemMod = syn::parse2(tokens)?;
+            if let Some((_, items)) = &mut masked.content {
+                mask_shadowing_items(items, ident, &mask)?;
+            }
+
+            tokens = replace_ident(masked.to_token_stream(), ident, &ty.to_token_stream());
+            tokens = replace_ident(tokens, &mask, &ident.to_token_stream());
+        }
+
+        let name = format_ident!("{}_{}", target.ident, self.name(), span = target.ident.span());
+        *target = syn::parse2::<ItemMod>(tokens)?;
+        target.ident = name;
+
+        Ok(())
+    }
+}
+
+fn replace_ident(tokens: TokenStream, ident: &Ident, substitute: &TokenStream) -> TokenStream {
+    recursive_descent_replace(&mut TokenBuffer::new2(tokens).begin(), ident, substitute)
+}
+
+fn declares_generic(generics: &Generics, ident: &Ident) -> bool {
+    generics.params.iter().any(| param | match param {
+        GenericParam::Type(param) => param.ident == *ident,
+        GenericParam::Const(param) => param.ident == *ident,
+        GenericParam::Lifetime(_) => false
+    })
+}
+
+// Renames `ident` to `mask` throughout each item declaring it as a generic parameter, descending
+// into nested modules, impls, traits & function bodies - such are restored once substituted
+fn mask_shadowing_items(items: &mut [Item], ident: &Ident, mask: &Ident) -> Result<()> {
+    for item in items {
+        let generics: Option<&Generics> = match &*item {
+            Item::Fn(item) => Some(&item.sig.generics),
+            Item::Impl(item) => Some(&item.generics),
+            Item::Trait(item) => Some(&item.generics),
+            Item::Struct(item) => Some(&item.generics),
+            Item::Enum(item) => Some(&item.generics),
+            Item::Union(item) => Some(&item.generics),
+            Item::Type(item) => Some(&item.generics),
+            _ => None
+        };
+
+        if generics.is_some_and(| generics | declares_generic(generics, ident)) {
+            *item = syn::parse2(replace_ident(item.to_token_stream(), ident, &mask.to_token_stream()))?;
+            continue;
+        }
+
+        match item {
+            Item::Mod(ItemMod { content: Some((_, items)), .. }) => mask_shadowing_items(items, ident, mask)?,
+            Item::Fn(item) => mask_shadowing_stmts(&mut item.block.stmts, ident, mask)?,
+            Item::Impl(item) => for impl_item in &mut item.items {
+                let ImplItem::Fn(impl_fn) = impl_item else { continue };
+                match declares_generic(&impl_fn.sig.generics, ident) {
+                    true => *impl_item = syn::parse2(
+                        replace_ident(impl_item.to_token_stream(), ident, &mask.to_token_stream())
+                    )?,
+                    false => mask_shadowing_stmts(&mut impl_fn.block.stmts, ident, mask)?
+                }
+            },
+            Item::Trait(item) => for trait_item in &mut item.items {
+                let TraitItem::Fn(trait_fn) = trait_item else { continue };
+                if declares_generic(&trait_fn.sig.generics, ident) {
+                    *trait_item = syn::parse2(
+                        replace_ident(trait_item.to_token_stream(), ident, &mask.to_token_stream())
+                    )?;
+                } else if let Some(block) = &mut trait_fn.default {
+                    mask_shadowing_stmts(&mut block.stmts, ident, mask)?;
+                }
+            },
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn mask_shadowing_stmts(stmts: &mut [Stmt], ident: &Ident, mask: &Ident) -> Result<()> {
+    for stmt in stmts {
+        if let Stmt::Item(item) = stmt {
+            mask_shadowing_items(std::slice::from_mut(item), ident, mask)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::quote;
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_accepts_single_assignment() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamForTypes>(quote!(T = [VecQueue, RingQueue])),
+            Ok(quote!(T = [VecQueue, RingQueue]))
+        );
+    }
+
+    #[test]
+    fn parse_accepts_many_assignments() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamForTypes>(quote!(T = [Vec<u8>], U = [u8, u16])),
+            Ok(quote!(T = [Vec<u8>] U = [u8, u16]))
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_on_missing_type_parameter() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamForTypes>(quote!([VecQueue])),
+            Err(error_spanned!("expected type parameter"))
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_on_missing_brackets() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamForTypes>(quote!(T = VecQueue)),
+            Err(error_spanned!("expected `[]`"))
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_on_empty_type_list() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamForTypes>(quote!(T = [])),
+            Err(error_spanned!("for_types(): expected at least one type"))
+        );
+    }
+
+    #[test]
+    fn instances_are_cartesian_product_of_assignments() {
+        let param: ParamForTypes = parse_quote!(T = [A, B], U = [C, D]);
+        let names: Vec<String> = param.instances().unwrap().iter().map(TypeInstance::name).collect();
+
+        assert_eq!(names, ["a_c", "a_d", "b_c", "b_d"]);
+    }
+
+    #[test]
+    fn instances_returns_error_on_duplicate_names() {
+        let param: ParamForTypes = parse_quote!(T = [VecQueue, vec_queue]);
+
+        assert!(param.instances().is_err());
+    }
+
+    #[test]
+    fn mutate_substitutes_and_renames_module() {
+        let mut target: ItemMod = parse_quote!{
+            mod conformance {
+                #[test]
+                fn push() {
+                    let queue = <T>::new();
+                }
+            }
+        };
+
+        let instance = TypeInstance(vec![(parse_quote!(T), parse_quote!(RingQueue<u8>))]);
+        assert_eq_mutate!(instance, &mut target, Ok(()));
+
+        assert_eq_tokens!(target, quote!{
+            mod conformance_ring_queue_u8 {
+                #[test]
+                fn push() {
+                    let queue = <RingQueue<u8> >::new();
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_skips_items_declaring_same_generic() {
+        let mut target: ItemMod = parse_quote!{
+            mod conformance {
+                fn helper<T: Default>() -> T {
+                    T::default()
+                }
+
+                impl Wrapper {
+                    fn wrap<const T: usize>(queue: T) -> [u8; T] {}
+                }
+
+                #[test]
+                fn push() {
+                    fn nested<T>(value: T) -> T { value }
+                    let queue = nested(<T>::new());
+                }
+            }
+        };
+
+        let instance = TypeInstance(vec![(parse_quote!(T), parse_quote!(RingQueue))]);
+        assert_eq_mutate!(instance, &mut target, Ok(()));
+
+        assert_eq_tokens!(target, quote!{
+            mod conformance_ring_queue {
+                fn helper<T: Default>() -> T {
+                    T::default()
+                }
+
+                impl Wrapper {
+                    fn wrap<const T: usize>(queue: T) -> [u8; T] {}
+                }
+
+                #[test]
+                fn push() {
+                    fn nested<T>(value: T) -> T { value }
+                    let queue = nested(<RingQueue>::new());
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_returns_error_on_non_inline_modules() {
+        let mut target: ItemMod = parse_quote!(mod conformance;);
+        let instance = TypeInstance(vec![(parse_quote!(T), parse_quote!(RingQueue))]);
+
+        assert_eq_mutate!(
+            instance, &mut target,
+            Err(error_spanned!("for_types() can only be applied to inline modules"))
+        );
+    }
+}
@@ -40,6 +40,25 @@ impl Mutate for ParamName {
 impl_unique!(ParamName);
 impl_param!(ParamName, 0);
 
+// Appended to generated test names, i.e by `each(...)` - unlike `ParamName`, suffixes needn't be identifiers
+#[derive(Clone)]
+pub(crate) struct ParamSuffix(pub Vec<String>);
+
+impl Mutate for ParamSuffix {
+    type Item = Signature;
+
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        for suffix in &self.0 {
+            target.ident = format_ident!("{}_{}", target.ident, suffix);
+        }
+
+        Ok(())
+    }
+}
+
+impl_unique!(ParamSuffix);
+impl_param!(ParamSuffix, iterable(0));
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -76,4 +95,12 @@ mod tests {
             Err(error_spanned!("expected test name"))
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn suffix_mutate_appends_each_suffix() {
+        let mut target: syn::Signature = syn::parse_quote!(fn test());
+        ParamSuffix(vec![String::from("0"), String::from("red")]).mutate(&mut target).unwrap();
+
+        assert_eq!(target.ident.to_string(), "test_0_red");
+    }
+}
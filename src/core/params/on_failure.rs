@@ -0,0 +1,90 @@
+use syn::{
+    Ident, ItemFn, Result,
+    parse::{
+        Parse, ParseStream
+    }
+};
+use crate::params::{
+    Mutate, macros::*
+};
+
+// Names the suite's `#[on_failure]` hook, called with a `sith_runtime::Failure` should a test panic
+#[derive(Clone)]
+pub(crate) struct ParamOnFailure(pub Ident);
+
+impl Parse for ParamOnFailure {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self(input.parse::<Ident>()?))
+    }
+}
+
+impl ParamOnFailure {
+    pub const IDENT: &'static str = "on_failure";
+}
+
+impl Mutate for ParamOnFailure {
+    type Item = ItemFn;
+
+    // The test (setup through to teardown) is run within `catch_unwind`, so the hook is called
+    // before the panic propagates. Must apply after setup & teardown, so as to wrap such - and
+    // before `ParamCase`, which declares the inputs reported
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        let name: String = target.sig.ident.to_string();
+        let hook: &Ident = &self.0;
+        let stmts = core::mem::take(&mut target.block.stmts);
+
+        *target.block = syn::parse_quote!({
+            match ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| { #(#stmts)* })) {
+                Ok(output) => output,
+                Err(payload) => {
+                    #hook(&::sith_runtime::Failure::new(#name, payload.as_ref(), __sith_case.values()));
+                    ::std::panic::resume_unwind(payload)
+                }
+            }
+        });
+
+        Ok(())
+    }
+}
+
+impl_unique!(ParamOnFailure);
+impl_param!(ParamOnFailure, 0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::{
+        quote, ToTokens
+    };
+    use syn::parse_quote;
+
+    #[test]
+    fn mutate_calls_hook_upon_panic() {
+        let mut target: ItemFn = parse_quote!{
+            fn my_test() {
+                body();
+            }
+        };
+
+        assert_eq_mutate!(ParamOnFailure(parse_quote!(dump)), &mut target, Ok(()));
+        assert_eq_tokens!(
+            target,
+            quote!{
+                fn my_test() {
+                    match ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(| | { body(); })) {
+                        Ok(output) => output,
+                        Err(payload) => {
+                            dump(&::sith_runtime::Failure::new("my_test", payload.as_ref(), __sith_case.values()));
+                            ::std::panic::resume_unwind(payload)
+                        }
+                    }
+                }
+            }
+        );
+    }
+}
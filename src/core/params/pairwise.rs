@@ -0,0 +1,390 @@
+use quote::ToTokens;
+
+use proc_macro2::TokenStream;
+use syn::{
+    Ident, Expr, ExprLit, Lit,
+    ItemFn, FnArg, Pat,
+    Token, Result,
+    parse::{
+        Parse, ParseStream
+    }
+};
+use crate::{
+    common::{
+        greedy_parse_with_delim,
+        macros::error_spanned
+    },
+    params::{
+        Mutate, macros::*,
+        with::{
+            ParamWith, parse_elements, element_names
+        }
+    }
+};
+
+// Candidate rows generated per row of the covering - the best of which is kept
+const CANDIDATES: usize = 16;
+
+#[derive(Clone)]
+enum PairwiseArg {
+    Values(Ident, Vec<Expr>),
+    Seed(u64)
+}
+
+impl Parse for PairwiseArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let Result::Ok(ident) = input.parse::<Ident>() else {
+            return Err(error_spanned!("expected test input", &input.span()));
+        };
+
+        input.parse::<Token![=]>()?;
+        match input.parse::<Expr>()? {
+            Expr::Lit(ExprLit { lit: Lit::Int(seed), .. }) if ident == "seed" => {
+                Ok(PairwiseArg::Seed(seed.base10_parse::<u64>()?))
+            },
+            expr => {
+                Ok(PairwiseArg::Values(ident, parse_elements("pairwise", expr)?))
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct ParamPairwise {
+    values: Vec<(Ident, Vec<Expr>)>,
+    seed: u64
+}
+
+impl Parse for ParamPairwise {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut param = Self { values: Vec::new(), seed: 0 };
+
+        for arg in greedy_parse_with_delim::<PairwiseArg, Token![,]>(input)? {
+            match arg {
+                PairwiseArg::Values(ident, values) => {
+                    if param.values.iter().any(| (existing, _) | existing.eq(&ident)) {
+                        return Err(error_spanned!(format!("pairwise(): duplicate input `{}`", ident), &ident));
+                    }
+
+                    param.values.push((ident, values));
+                },
+                PairwiseArg::Seed(seed) => {
+                    param.seed = seed;
+                }
+            }
+        }
+
+        if param.values.is_empty() {
+            return Err(error_spanned!("pairwise(): expected at least one input", &input.span()));
+        }
+
+        Ok(param)
+    }
+}
+
+impl ParamPairwise {
+    // Generates a `with(...)` per row of the covering, binding values to inputs by name, along
+    // with name suffixes derived from the row's values - so names stay stable between builds
+    pub fn expand(&self, target: &ItemFn) -> Result<Vec<(ParamWith, Vec<String>)>> {
+        let mut order: Vec<usize> = Vec::with_capacity(target.sig.inputs.len());
+        for input in &target.sig.inputs {
+            let FnArg::Typed(typed) = input else {
+                return Err(error_spanned!("invalid parameter", input));
+            };
+
+            let Pat::Ident(pat) = &*typed.pat else {
+                return Err(error_spanned!("pairwise(): inputs must be bound to an identifier", &typed.pat));
+            };
+
+            let Some(position) = self.values.iter().position(| (ident, _) | ident.eq(&pat.ident)) else {
+                return Err(error_spanned!(format!("pairwise(): no values for input `{}`", pat.ident), &pat.ident));
+            };
+
+            order.push(position);
+        }
+
+        if let Some((ident, _)) = self.values.iter().enumerate().find(| (i, _) | !order.contains(i)).map(| (_, value) | value) {
+            return Err(error_spanned!(format!("pairwise(): no input `{}`", ident), ident));
+        }
+
+        let sizes: Vec<usize> = self.values.iter().map(| (_, values) | values.len()).collect();
+        let names: Vec<Vec<String>> = self.values.iter().map(| (_, values) | element_names(values)).collect();
+
+        Ok(covering(&sizes, self.seed).into_iter().map(| row | (
+            order.iter().map(| &i | self.values[i].1[row[i]].clone()).collect(),
+            order.iter().map(| &i | names[i][row[i]].clone()).collect()
+        )).collect())
+    }
+}
+
+impl Mutate for ParamPairwise {
+    type Item = ItemFn;
+
+    fn mutate(&self, _: &mut Self::Item) -> Result<()> {
+        Err(error_spanned!("pairwise() must be expanded prior to binding inputs", self))
+    }
+}
+
+impl ToTokens for ParamPairwise {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for (ident, values) in &self.values {
+            quote::quote!(#ident = [#(#values),*]).to_tokens(tokens);
+        }
+    }
+}
+
+impl_unique!(ParamPairwise);
+
+impl core::fmt::Debug for ParamPairwise {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ParamPairwise")
+            .field(&self.to_token_stream().to_string())
+            .field(&self.seed)
+            .finish()
+    }
+}
+
+// xorshift64*, so coverings are reproducible without depending on a rand crate
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self((seed ^ 0x9E37_79B9_7F4A_7C15).max(1))
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+
+        (self.0.wrapping_mul(0x2545_F491_4F6C_DD1D) % bound as u64) as usize
+    }
+}
+
+// Tracks which value pairs of each pair of inputs are yet to appear in a row
+struct Uncovered {
+    sizes: Vec<usize>,
+    pairs: Vec<Vec<Vec<bool>>>,
+    remaining: usize
+}
+
+impl Uncovered {
+    fn new(sizes: &[usize]) -> Self {
+        let pairs: Vec<Vec<Vec<bool>>> = (0..sizes.len()).map(| i | {
+            (0..sizes.len()).map(| j | vec![i < j; sizes[i] * sizes[j]]).collect()
+        }).collect();
+
+        let remaining = (0..sizes.len()).flat_map(| i | (i + 1..sizes.len()).map(move | j | (i, j)))
+            .map(| (i, j) | sizes[i] * sizes[j])
+            .sum();
+
+        Self { sizes: sizes.to_vec(), pairs, remaining }
+    }
+
+    fn get(&self, (i, vi): (usize, usize), (j, vj): (usize, usize)) -> bool {
+        match i < j {
+            true => self.pairs[i][j][vi * self.sizes[j] + vj],
+            false => self.pairs[j][i][vj * self.sizes[i] + vi]
+        }
+    }
+
+    fn nth(&self, mut n: usize) -> ((usize, usize), (usize, usize)) {
+        for i in 0..self.sizes.len() {
+            for j in i + 1..self.sizes.len() {
+                for (index, _) in self.pairs[i][j].iter().enumerate().filter(| (_, uncovered) | **uncovered) {
+                    if n == 0 {
+                        return ((i, index / self.sizes[j]), (j, index % self.sizes[j]));
+                    }
+
+                    n -= 1;
+                }
+            }
+        }
+
+        unreachable!("n exceeds the number of uncovered pairs")
+    }
+
+    fn count(&self, row: &[usize]) -> usize {
+        (0..row.len()).flat_map(| i | (i + 1..row.len()).map(move | j | (i, j)))
+            .filter(| &(i, j) | self.get((i, row[i]), (j, row[j])))
+            .count()
+    }
+
+    fn cover(&mut self, row: &[usize]) {
+        for i in 0..row.len() {
+            for j in i + 1..row.len() {
+                let pair = &mut self.pairs[i][j][row[i] * self.sizes[j] + row[j]];
+                if *pair {
+                    *pair = false;
+                    self.remaining -= 1;
+                }
+            }
+        }
+    }
+}
+
+// Greedily generates rows (as value indices per input) until every pair of values of every
+// pair of inputs appears at least once. Each row is seeded with an uncovered pair, so progress
+// is guaranteed - the remaining inputs take whichever value covers the most uncovered pairs
+fn covering(sizes: &[usize], seed: u64) -> Vec<Vec<usize>> {
+    if sizes.len() < 2 {
+        return (0..sizes.first().copied().unwrap_or(0)).map(| value | vec![value]).collect();
+    }
+
+    let mut rng = Rng::new(seed);
+    let mut uncovered = Uncovered::new(sizes);
+    let mut rows: Vec<Vec<usize>> = Vec::new();
+
+    while uncovered.remaining > 0 {
+        let mut best: Option<(usize, Vec<usize>)> = None;
+
+        for _ in 0..CANDIDATES {
+            let ((i, vi), (j, vj)) = uncovered.nth(rng.below(uncovered.remaining));
+            let mut row: Vec<Option<usize>> = vec![None; sizes.len()];
+            row[i] = Some(vi);
+            row[j] = Some(vj);
+
+            let mut order: Vec<usize> = (0..sizes.len()).filter(| &k | k != i && k != j).collect();
+            for k in (1..order.len()).rev() {
+                order.swap(k, rng.below(k + 1));
+            }
+
+            for k in order {
+                let gains: Vec<usize> = (0..sizes[k]).map(| value | {
+                    row.iter().enumerate()
+                        .filter_map(| (other, assigned) | assigned.map(| assigned | (other, assigned)))
+                        .filter(| &assigned | uncovered.get((k, value), assigned))
+                        .count()
+                }).collect();
+
+                let max = gains.iter().copied().max().unwrap_or(0);
+                let ties: Vec<usize> = (0..sizes[k]).filter(| &value | gains[value] == max).collect();
+                row[k] = Some(ties[rng.below(ties.len())]);
+            }
+
+            let row: Vec<usize> = row.into_iter().map(Option::unwrap_or_default).collect();
+            let count = uncovered.count(&row);
+            if best.as_ref().map_or(true, | (best, _) | count > *best) {
+                best = Some((count, row));
+            }
+        }
+
+        let (_, row) = best.unwrap();
+        uncovered.cover(&row);
+        rows.push(row);
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::tests::macros::*;
+
+    use quote::quote;
+    use syn::parse_quote;
+
+    fn assert_covers_all_pairs(sizes: &[usize], rows: &[Vec<usize>]) {
+        for i in 0..sizes.len() {
+            for j in i + 1..sizes.len() {
+                for vi in 0..sizes[i] {
+                    for vj in 0..sizes[j] {
+                        assert!(
+                            rows.iter().any(| row | row[i] == vi && row[j] == vj),
+                            "pair ({}={}, {}={}) uncovered", i, vi, j, vj
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse_accepts_values_and_seed() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamPairwise>(quote!(a = [1, 2], b = 0..2, seed = 7)),
+            Ok(quote!(a = [1, 2] b = [0, 1]))
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_on_duplicate_inputs() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamPairwise>(quote!(a = [1], a = [2])),
+            Err(error_spanned!("pairwise(): duplicate input `a`"))
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_without_inputs() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamPairwise>(quote!(seed = 1)),
+            Err(error_spanned!("pairwise(): expected at least one input"))
+        );
+    }
+
+    #[test]
+    fn covering_covers_every_pair() {
+        let sizes = [4, 4, 4, 4, 4];
+        let rows = covering(&sizes, 0);
+
+        assert_covers_all_pairs(&sizes, &rows);
+        // Exhaustively, this would be 4^5 = 1024 rows
+        assert!(rows.len() <= 24, "{} rows generated", rows.len());
+    }
+
+    #[test]
+    fn covering_covers_mixed_sizes() {
+        let sizes = [2, 5, 1, 3];
+        for seed in 0..8 {
+            assert_covers_all_pairs(&sizes, &covering(&sizes, seed));
+        }
+    }
+
+    #[test]
+    fn covering_is_deterministic_per_seed() {
+        let sizes = [3, 3, 3, 3];
+
+        assert_eq!(covering(&sizes, 42), covering(&sizes, 42));
+        assert!((0..8).any(| seed | covering(&sizes, seed) != covering(&sizes, 42)));
+    }
+
+    #[test]
+    fn covering_lists_values_of_single_input() {
+        assert_eq!(covering(&[3], 0), [[0], [1], [2]]);
+    }
+
+    #[test]
+    fn expand_binds_values_in_declaration_order() {
+        let param: ParamPairwise = parse_quote!(b = [Color::Red], a = [1]);
+        let target: ItemFn = parse_quote!(fn test(a: u8, b: Color) {});
+
+        let expanded: Vec<(ParamWith, Vec<String>)> = param.expand(&target).unwrap();
+        assert_eq!(expanded.len(), 1);
+        assert_eq_tokens!(expanded[0].0, quote!(1 Color::Red));
+        assert_eq!(expanded[0].1, ["1", "color_red"]);
+    }
+
+    #[test]
+    fn expand_returns_error_on_unbound_input() {
+        let param: ParamPairwise = parse_quote!(a = [1]);
+        let target: ItemFn = parse_quote!(fn test(a: u8, b: u8) {});
+
+        assert_eq_parsed!(
+            param.expand(&target).map(| _ | quote!()),
+            Err(error_spanned!("pairwise(): no values for input `b`"))
+        );
+    }
+
+    #[test]
+    fn expand_returns_error_on_unknown_input() {
+        let param: ParamPairwise = parse_quote!(a = [1], c = [2]);
+        let target: ItemFn = parse_quote!(fn test(a: u8) {});
+
+        assert_eq_parsed!(
+            param.expand(&target).map(| _ | quote!()),
+            Err(error_spanned!("pairwise(): no input `c`"))
+        );
+    }
+}
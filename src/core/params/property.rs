@@ -0,0 +1,241 @@
+use quote::ToTokens;
+
+use proc_macro2::TokenStream;
+use syn::{
+    Ident, Expr, LitInt,
+    ItemFn, FnArg, Pat, Type,
+    ReturnType, Token, Result,
+    parse::{
+        Parse, ParseStream
+    },
+    punctuated::Punctuated
+};
+use crate::{
+    common::{
+        greedy_parse_with_delim,
+        macros::error_spanned
+    },
+    params::{
+        Mutate, macros::*
+    }
+};
+
+#[derive(Clone)]
+struct PropertyArg(Ident, LitInt);
+
+impl Parse for PropertyArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse::<Ident>()?;
+        if name != "cases" {
+            return Err(error_spanned!(format!("property(): unrecognized arg `{}`", name), &name));
+        }
+
+        input.parse::<Token![=]>()?;
+        let value = input.parse::<LitInt>()?;
+        value.base10_parse::<u32>()?;
+
+        Ok(Self(name, value))
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct ParamProperty {
+    cases: Option<LitInt>,
+    // Strategies declared via `with(...)`, see `TestCase::expand`
+    pub strategies: Option<Vec<Expr>>
+}
+
+impl Parse for ParamProperty {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let args: Vec<PropertyArg> = greedy_parse_with_delim::<PropertyArg, Token![,]>(input)?;
+        if let Some(PropertyArg(name, _)) = args.get(1) {
+            return Err(error_spanned!("property(): duplicate arg `cases`", name));
+        }
+
+        Ok(Self {
+            cases: args.into_iter().next().map(| PropertyArg(_, value) | value),
+            strategies: None
+        })
+    }
+}
+
+impl ParamProperty {
+    pub const DEFAULT_CASES: u32 = 256;
+}
+
+impl Mutate for ParamProperty {
+    type Item = ItemFn;
+
+    // Inputs are generated per case, so the test body is moved into a closure over
+    // such - `sith_runtime::property::check` then runs, and shrinks, the property
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        let inputs: Punctuated<FnArg, Token![,]> = core::mem::take(&mut target.sig.inputs);
+
+        let mut pats: Vec<Pat> = Vec::with_capacity(inputs.len());
+        let mut tys: Vec<Type> = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            let FnArg::Typed(typed) = input else {
+                return Err(error_spanned!("invalid parameter", &input));
+            };
+
+            pats.push(*typed.pat);
+            tys.push(*typed.ty);
+        }
+
+        let strategies: Vec<TokenStream> = match &self.strategies {
+            Some(strategies) if strategies.len() != tys.len() => {
+                return Err(error_spanned!(
+                    format!("property(): {} fn inputs but {} strategies declared", tys.len(), strategies.len()),
+                    &target.sig.ident
+                ));
+            },
+            Some(strategies) => strategies.iter().zip(&tys).map(| (strategy, ty) | match strategy {
+                // `_` generates any value of the input's type
+                Expr::Infer(_) => quote::quote!(::sith_runtime::any::<#ty>()),
+                strategy => strategy.to_token_stream()
+            }).collect(),
+            None => tys.iter().map(| ty | quote::quote!(::sith_runtime::any::<#ty>())).collect()
+        };
+
+        let name: String = target.sig.ident.to_string();
+        let described: String = format!(
+            "({})",
+            pats.iter().map(| pat | pat.to_token_stream().to_string()).collect::<Vec<String>>().join(", ")
+        );
+        let cases: TokenStream = match &self.cases {
+            Some(cases) => cases.to_token_stream(),
+            None => Self::DEFAULT_CASES.to_token_stream()
+        };
+
+        let block = &target.block;
+        let output: TokenStream = match core::mem::replace(&mut target.sig.output, ReturnType::Default) {
+            ReturnType::Type(arrow, ty) => quote::quote!(#arrow #ty),
+            ReturnType::Default => TokenStream::new()
+        };
+
+        *target.block = syn::parse_quote!({
+            ::sith_runtime::property::check(
+                #name, #described, #cases,
+                (#(#strategies,)*),
+                | (#(#pats,)*): (#(#tys,)*) | #output #block
+            );
+        });
+
+        Ok(())
+    }
+}
+
+impl ToTokens for ParamProperty {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Some(cases) = &self.cases {
+            quote::quote!(cases = #cases).to_tokens(tokens);
+        }
+    }
+}
+
+impl_unique!(ParamProperty);
+
+impl core::fmt::Debug for ParamProperty {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ParamProperty")
+            .field(&self.to_token_stream().to_string())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::quote;
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_accepts_cases() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamProperty>(quote!(cases = 16)),
+            Ok(quote!(cases = 16))
+        );
+    }
+
+    #[test]
+    fn parse_accepts_no_args() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamProperty>(quote!()),
+            Ok(quote!())
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_on_unrecognized_arg() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamProperty>(quote!(iterations = 16)),
+            Err(error_spanned!("property(): unrecognized arg `iterations`"))
+        );
+    }
+
+    #[test]
+    fn mutate_generates_inputs_from_types() {
+        let mut target: ItemFn = parse_quote!{
+            fn roundtrip(value: u32, mut bytes: Vec<u8>) {
+                body();
+            }
+        };
+
+        let param: ParamProperty = parse_quote!(cases = 16);
+        assert_eq_mutate!(param, &mut target, Ok(()));
+
+        assert_eq_tokens!(target, quote!{
+            fn roundtrip() {
+                ::sith_runtime::property::check(
+                    "roundtrip", "(value, mut bytes)", 16,
+                    (::sith_runtime::any::<u32>(), ::sith_runtime::any::<Vec<u8> >(),),
+                    | (value, mut bytes,): (u32, Vec<u8>,) | {
+                        body();
+                    }
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_uses_declared_strategies() {
+        let mut target: ItemFn = parse_quote!{
+            fn bounded(value: u8, other: u16) -> Result<(), String> {
+                Ok(())
+            }
+        };
+
+        let mut param: ParamProperty = parse_quote!();
+        param.strategies = Some(vec![parse_quote!(0..100u8), parse_quote!(_)]);
+        assert_eq_mutate!(param, &mut target, Ok(()));
+
+        assert_eq_tokens!(target, quote!{
+            fn bounded() {
+                ::sith_runtime::property::check(
+                    "bounded", "(value, other)", 256u32,
+                    (0..100u8, ::sith_runtime::any::<u16>(),),
+                    | (value, other,): (u8, u16,) | -> Result<(), String> {
+                        Ok(())
+                    }
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_returns_error_on_strategy_count_mismatch() {
+        let mut target: ItemFn = parse_quote!(fn bounded(value: u8) {});
+
+        let mut param: ParamProperty = parse_quote!();
+        param.strategies = Some(Vec::new());
+        assert_eq_mutate!(
+            param, &mut target,
+            Err(error_spanned!("property(): 1 fn inputs but 0 strategies declared"))
+        );
+    }
+}
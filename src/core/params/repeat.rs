@@ -0,0 +1,187 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
+use syn::{
+    Ident, LitInt, ItemFn, Token, Result,
+    ext::IdentExt,
+    parse::{
+        Parse, ParseStream
+    }
+};
+use crate::{
+    common::macros::error_spanned,
+    params::{
+        Mutate, macros::*
+    }
+};
+
+#[derive(Clone)]
+pub(crate) enum ParamRepeat {
+    // Runs the test `count` times within the one test - setup & teardown
+    // per iteration, unless `shared_setup` is given
+    Iterations(LitInt, Option<Ident>),
+    // Emits `count` distinct tests, see `TestCase::expand`
+    Expand(Ident, LitInt)
+}
+
+impl Parse for ParamRepeat {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Ident) && input.peek2(Token![=]) {
+            let name = input.parse::<Ident>()?;
+            if name != "expand" {
+                return Err(error_spanned!(format!("repeat(): unrecognized arg `{}`", name), &name));
+            }
+
+            input.parse::<Token![=]>()?;
+            return Ok(Self::Expand(name, parse_count(input)?));
+        }
+
+        let count: LitInt = parse_count(input)?;
+        if input.is_empty() {
+            return Ok(Self::Iterations(count, None));
+        }
+
+        input.parse::<Token![,]>()?;
+        let shared_setup = input.parse::<Ident>()?;
+        if shared_setup != "shared_setup" {
+            return Err(error_spanned!(format!("repeat(): unrecognized arg `{}`", shared_setup), &shared_setup));
+        }
+
+        Ok(Self::Iterations(count, Some(shared_setup)))
+    }
+}
+
+fn parse_count(input: ParseStream) -> Result<LitInt> {
+    let count = input.parse::<LitInt>()?;
+    if count.base10_parse::<u64>()? == 0 {
+        return Err(error_spanned!("repeat(): count must be at least 1", &count));
+    }
+
+    Ok(count)
+}
+
+impl ParamRepeat {
+    pub fn is_shared_setup(&self) -> bool {
+        matches!(self, Self::Iterations(_, Some(_)))
+    }
+
+    pub fn expansions(&self) -> Option<u64> {
+        match self {
+            Self::Expand(_, count) => count.base10_parse::<u64>().ok(),
+            Self::Iterations(..) => None
+        }
+    }
+}
+
+impl Mutate for ParamRepeat {
+    type Item = ItemFn;
+
+    // The test is moved into a closure, run per iteration. Must apply after setup & teardown
+    // (or before, if shared) and before `retries`, which re-runs every iteration
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        let Self::Iterations(count, _) = self else {
+            return Err(error_spanned!("repeat(expand = ...) must be expanded prior to mutation", self));
+        };
+
+        if let Some(asyncness) = &target.sig.asyncness {
+            return Err(error_spanned!("repeat() can't be applied to async tests", asyncness));
+        }
+
+        let name: String = target.sig.ident.unraw().to_string();
+        let count: u64 = count.base10_parse::<u64>()?;
+        let stmts = core::mem::take(&mut target.block.stmts);
+
+        *target.block = syn::parse_quote!({
+            ::sith_runtime::repeat::run(#name, #count, || { #(#stmts)* })
+        });
+
+        Ok(())
+    }
+}
+
+impl ToTokens for ParamRepeat {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Iterations(count, None) => count.to_tokens(tokens),
+            Self::Iterations(count, Some(shared_setup)) => quote::quote!(#count, #shared_setup).to_tokens(tokens),
+            Self::Expand(name, count) => quote::quote!(#name = #count).to_tokens(tokens)
+        }
+    }
+}
+
+impl_unique!(ParamRepeat);
+
+impl core::fmt::Debug for ParamRepeat {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ParamRepeat")
+            .field(&self.to_token_stream().to_string())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::quote;
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_accepts_all_forms() {
+        assert_eq_parsed!(syn::parse2::<ParamRepeat>(quote!(1000)), Ok(quote!(1000)));
+        assert_eq_parsed!(syn::parse2::<ParamRepeat>(quote!(10, shared_setup)), Ok(quote!(10, shared_setup)));
+        assert_eq_parsed!(syn::parse2::<ParamRepeat>(quote!(expand = 10)), Ok(quote!(expand = 10)));
+    }
+
+    #[test]
+    fn parse_returns_error_on_zero_count() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamRepeat>(quote!(0)),
+            Err(error_spanned!("repeat(): count must be at least 1"))
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_on_unrecognized_args() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamRepeat>(quote!(10, shared)),
+            Err(error_spanned!("repeat(): unrecognized arg `shared`"))
+        );
+        assert_eq_parsed!(
+            syn::parse2::<ParamRepeat>(quote!(count = 10)),
+            Err(error_spanned!("repeat(): unrecognized arg `count`"))
+        );
+    }
+
+    #[test]
+    fn mutate_runs_test_per_iteration() {
+        let mut target: ItemFn = parse_quote!{
+            fn stress() {
+                body();
+            }
+        };
+
+        let param: ParamRepeat = parse_quote!(1000);
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            fn stress() {
+                ::sith_runtime::repeat::run("stress", 1000u64, | | { body(); })
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_returns_error_on_expand() {
+        let mut target: ItemFn = parse_quote!(fn stress() {});
+
+        let param: ParamRepeat = parse_quote!(expand = 10);
+        assert_eq_mutate!(
+            param, &mut target,
+            Err(error_spanned!("repeat(expand = ...) must be expanded prior to mutation"))
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use syn::{
+    LitInt, ItemFn, Result,
+    ext::IdentExt,
+    parse::{
+        Parse, ParseStream
+    }
+};
+use crate::{
+    common::macros::error_spanned,
+    params::{
+        Mutate, macros::*
+    }
+};
+
+#[derive(Clone)]
+pub(crate) struct ParamRetries(pub LitInt);
+
+impl Parse for ParamRetries {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let retries = input.parse::<LitInt>()?;
+        retries.base10_parse::<u32>()?;
+
+        Ok(Self(retries))
+    }
+}
+
+impl Mutate for ParamRetries {
+    type Item = ItemFn;
+
+    // Setup through to teardown is moved into a closure, so may be re-run per attempt. Must
+    // apply after setup & teardown, and before `on_failure` - which is called only once all
+    // attempts have failed
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        if let Some(asyncness) = &target.sig.asyncness {
+            return Err(error_spanned!("retries can't be applied to async tests", asyncness));
+        }
+
+        let name: String = target.sig.ident.unraw().to_string();
+        let retries: u32 = self.0.base10_parse::<u32>()?;
+        let stmts = core::mem::take(&mut target.block.stmts);
+
+        *target.block = syn::parse_quote!({
+            ::sith_runtime::retry::run(#name, #retries, || { #(#stmts)* })
+        });
+
+        Ok(())
+    }
+}
+
+impl_unique!(ParamRetries);
+impl_param!(ParamRetries, 0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::{
+        quote, ToTokens
+    };
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_returns_error_on_negative_retries() {
+        assert!(syn::parse2::<ParamRetries>(quote!(-1)).is_err());
+    }
+
+    #[test]
+    fn mutate_reruns_test_per_attempt() {
+        let mut target: ItemFn = parse_quote!{
+            fn flaky() {
+                body();
+            }
+        };
+
+        assert_eq_mutate!(ParamRetries(parse_quote!(3)), &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            fn flaky() {
+                ::sith_runtime::retry::run("flaky", 3u32, | | { body(); })
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_returns_error_on_async_tests() {
+        let mut target: ItemFn = parse_quote!(async fn flaky() {});
+
+        assert_eq_mutate!(
+            ParamRetries(parse_quote!(3)), &mut target,
+            Err(error_spanned!("retries can't be applied to async tests"))
+        );
+    }
+}
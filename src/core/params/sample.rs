@@ -0,0 +1,125 @@
+use syn::{
+    ItemFn, LitInt, Stmt, Result,
+    ext::IdentExt,
+    parse::{
+        Parse, ParseStream
+    }
+};
+use crate::{
+    common::macros::error_spanned,
+    params::{
+        Mutate, macros::*,
+        skip::skip_test
+    }
+};
+
+// `sample(N)`, or sampling by `SITH_SAMPLE` alone (without a count) - which, once the test case
+// is expanded, is given the index of each case of those expanded, from the test named `group`
+#[derive(Clone)]
+pub(crate) struct ParamSample {
+    pub count: Option<LitInt>,
+    group: String,
+    index: usize,
+    cases: usize
+}
+
+impl Parse for ParamSample {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let count = input.parse::<LitInt>()?;
+        if count.base10_parse::<usize>()? == 0 {
+            return Err(error_spanned!("sample(): count must be at least 1", &count));
+        }
+
+        Ok(Self { count: Some(count), group: String::new(), index: 0, cases: 1 })
+    }
+}
+
+impl ParamSample {
+    // Samples case `index` of the `cases` expanded from the test case of `group`
+    pub fn of_case(count: Option<LitInt>, group: String, index: usize, cases: usize) -> Self {
+        Self { count, group, index, cases }
+    }
+}
+
+impl Mutate for ParamSample {
+    type Item = ItemFn;
+
+    // Sampled at runtime, so `SITH_SAMPLE` & `SITH_SAMPLE_SEED` may be changed without a rebuild
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        if self.cases < 2 {
+            return Ok(());
+        }
+
+        let name: String = target.sig.ident.unraw().to_string();
+        let (group, index, cases) = (&self.group, self.index, self.cases);
+        let count = match &self.count {
+            Some(count) => quote::quote!(::core::option::Option::Some(#count)),
+            None => quote::quote!(::core::option::Option::None)
+        };
+        let skip: Stmt = skip_test(target);
+
+        target.block.stmts.insert(0, syn::parse_quote!(
+            if ::sith_runtime::sample::is_sampled_out(::core::module_path!(), #name, #group, #index, #cases, #count) {
+                #skip
+            }
+        ));
+
+        Ok(())
+    }
+}
+
+impl_unique!(ParamSample);
+impl_param!(ParamSample, count);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::{
+        quote, ToTokens
+    };
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_returns_error_on_zero_count() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamSample>(quote!(0)),
+            Err(error_spanned!("sample(): count must be at least 1"))
+        );
+    }
+
+    #[test]
+    fn mutate_samples_case_at_runtime() {
+        let mut target: ItemFn = parse_quote!{
+            fn adds_1() {
+                body();
+            }
+        };
+
+        let param: ParamSample = ParamSample::of_case(Some(parse_quote!(50)), String::from("adds"), 1, 64);
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            fn adds_1() {
+                if ::sith_runtime::sample::is_sampled_out(
+                    ::core::module_path!(), "adds_1", "adds", 1usize, 64usize, ::core::option::Option::Some(50)
+                ) {
+                    return ::sith_runtime::skip::Skipped::skipped();
+                }
+                body();
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_ignores_single_cases() {
+        let mut target: ItemFn = parse_quote!(fn adds() {});
+
+        let param: ParamSample = ParamSample::of_case(None, String::from("adds"), 0, 1);
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!(fn adds() {}));
+    }
+}
@@ -0,0 +1,134 @@
+use syn::{
+    Ident, ItemFn, Expr, ReturnType, Result,
+    parse::{
+        Parse, ParseStream
+    }
+};
+use crate::{
+    common::macros::error_spanned,
+    params::{
+        Mutate, macros::*
+    }
+};
+
+#[derive(Clone)]
+pub(crate) struct ParamSeed(pub Ident);
+
+impl Parse for ParamSeed {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse::<Ident>()?;
+        if name != Self::IDENT {
+            return Err(error_spanned!("expected `seed`", &name));
+        }
+
+        Ok(Self(name))
+    }
+}
+
+impl ParamSeed {
+    pub const IDENT: &'static str = "seed";
+}
+
+impl Mutate for ParamSeed {
+    type Item = ItemFn;
+
+    // Binds `seed` ahead of all other statements, so setup & inputs may derive from it. The guard
+    // lives until the test returns, reporting the seed should anything prior panic - or the
+    // output fail, hence the body is wrapped so early returns are checked too
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        let name: String = target.sig.ident.to_string();
+        let seed: &Ident = &self.0;
+        let stmts = core::mem::take(&mut target.block.stmts);
+
+        let body: Expr = match (&target.sig.asyncness, &target.sig.output) {
+            (Some(_), _) => syn::parse_quote!(async move { #(#stmts)* }.await),
+            (None, ReturnType::Default) => syn::parse_quote!((|| { #(#stmts)* })()),
+            (None, ReturnType::Type(_, output)) => syn::parse_quote!((|| -> #output { #(#stmts)* })())
+        };
+
+        *target.block = syn::parse_quote!({
+            let __sith_seed = ::sith_runtime::seed::Seed::new(#name);
+            let #seed: u64 = __sith_seed.value();
+            __sith_seed.check(#body)
+        });
+
+        Ok(())
+    }
+}
+
+impl_unique!(ParamSeed);
+impl_param!(ParamSeed, 0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::{
+        quote, ToTokens
+    };
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_accepts_seed() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamSeed>(quote!(seed)),
+            Ok(quote!(seed))
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_on_other_idents() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamSeed>(quote!(sed)),
+            Err(error_spanned!("expected `seed`"))
+        );
+    }
+
+    #[test]
+    fn mutate_binds_seed_before_body() {
+        let mut target: ItemFn = parse_quote!{
+            fn shuffles() {
+                let values = shuffle(seed);
+            }
+        };
+
+        assert_eq_mutate!(ParamSeed(parse_quote!(seed)), &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            fn shuffles() {
+                let __sith_seed = ::sith_runtime::seed::Seed::new("shuffles");
+                let seed: u64 = __sith_seed.value();
+                __sith_seed.check((| | { let values = shuffle(seed); })())
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_checks_output_of_fallible_and_async_tests() {
+        let mut target: ItemFn = parse_quote!{
+            fn shuffles() -> Result<(), String> {
+                shuffle(seed)?;
+                Ok(())
+            }
+        };
+
+        assert_eq_mutate!(ParamSeed(parse_quote!(seed)), &mut target, Ok(()));
+        assert_eq_tokens!(target.block.stmts[2], quote!{
+            __sith_seed.check((| | -> Result<(), String> { shuffle(seed)?; Ok(()) })())
+        });
+
+        let mut target: ItemFn = parse_quote!{
+            async fn shuffles() {
+                shuffle(seed).await;
+            }
+        };
+
+        assert_eq_mutate!(ParamSeed(parse_quote!(seed)), &mut target, Ok(()));
+        assert_eq_tokens!(target.block.stmts[2], quote!{
+            __sith_seed.check(async move { shuffle(seed).await; }.await)
+        });
+    }
+}
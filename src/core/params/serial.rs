@@ -0,0 +1,134 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
+use syn::{
+    Ident, ItemFn, Result,
+    token::Paren,
+    parse::{
+        Parse, ParseStream
+    }
+};
+use crate::{
+    common::macros::error_spanned,
+    params::{
+        Mutate, parse_param_args, macros::*
+    }
+};
+
+#[derive(Clone)]
+pub(crate) struct ParamSerial(pub Ident, pub Option<Ident>);
+
+impl Parse for ParamSerial {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse::<Ident>()?;
+        if name != Self::IDENT {
+            return Err(error_spanned!("expected `serial`", &name));
+        }
+
+        Self::parse_group(name, input)
+    }
+}
+
+impl ParamSerial {
+    pub const IDENT: &'static str = "serial";
+
+    // Parses the optional `(group)` following `serial`
+    pub fn parse_group(name: Ident, input: ParseStream) -> Result<Self> {
+        if !input.peek(Paren) {
+            return Ok(Self(name, None));
+        }
+
+        Ok(Self(name, Some(parse_param_args::<Ident>(input)?)))
+    }
+}
+
+impl Mutate for ParamSerial {
+    type Item = ItemFn;
+
+    // Locks ahead of all other statements, so the lock is held from setup through to teardown
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        let group: String = self.1.as_ref().map(Ident::to_string).unwrap_or_default();
+
+        target.block.stmts.insert(0, syn::parse_quote!(
+            let __sith_serial = ::sith_runtime::serial::lock(#group);
+        ));
+
+        Ok(())
+    }
+}
+
+impl ToTokens for ParamSerial {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+        if let Some(group) = &self.1 {
+            quote::quote!((#group)).to_tokens(tokens);
+        }
+    }
+}
+
+impl_unique!(ParamSerial);
+
+impl core::fmt::Debug for ParamSerial {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ParamSerial")
+            .field(&self.to_token_stream().to_string())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::quote;
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_accepts_serial_with_optional_group() {
+        assert_eq_parsed!(syn::parse2::<ParamSerial>(quote!(serial)), Ok(quote!(serial)));
+        assert_eq_parsed!(syn::parse2::<ParamSerial>(quote!(serial(env))), Ok(quote!(serial(env))));
+    }
+
+    #[test]
+    fn parse_returns_error_on_other_idents() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamSerial>(quote!(serialize)),
+            Err(error_spanned!("expected `serial`"))
+        );
+    }
+
+    #[test]
+    fn mutate_locks_group_before_body() {
+        let mut target: ItemFn = parse_quote!{
+            fn sets_env() {
+                body();
+            }
+        };
+
+        let param: ParamSerial = parse_quote!(serial(env));
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            fn sets_env() {
+                let __sith_serial = ::sith_runtime::serial::lock("env");
+                body();
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_locks_unnamed_group() {
+        let mut target: ItemFn = parse_quote!(fn sets_env() {});
+
+        let param: ParamSerial = parse_quote!(serial);
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            fn sets_env() {
+                let __sith_serial = ::sith_runtime::serial::lock("");
+            }
+        });
+    }
+}
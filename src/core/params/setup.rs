@@ -1,5 +1,8 @@
 use syn::{
-    Stmt, Block, Result
+    Stmt, Block, Result,
+    parse::{
+        Parse, ParseStream
+    }
 };
 use crate::params::{
     Mutate, macros::*
@@ -8,6 +11,12 @@ use crate::params::{
 #[derive(Clone)]
 pub struct ParamSetup(pub Vec<Stmt>);
 
+impl Parse for ParamSetup {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self(Block::parse_within(input)?))
+    }
+}
+
 impl Mutate for ParamSetup {
     type Item = Block;
 
@@ -87,6 +96,14 @@ mod tests {
         });
     }
 
+    #[test]
+    fn parse_accepts_statements() {
+        let param: ParamSetup = syn::parse2(quote!(let a = 1; foo();)).unwrap();
+
+        assert_eq!(param.0.len(), 2);
+        assert_eq!(param.to_token_stream().to_string(), quote!(let a = 1; foo();).to_string());
+    }
+
     #[test]
     fn parameter_is_unique() {
         let first = ParamSetup(Vec::new());
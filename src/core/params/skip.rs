@@ -0,0 +1,275 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
+use syn::{
+    Expr, ExprLit, Lit, LitStr, ItemFn, Meta, Stmt, Token, Result,
+    ext::IdentExt,
+    punctuated::Punctuated,
+    parse::{
+        Parse, ParseStream
+    }
+};
+use crate::{
+    common::{
+        attribute_name_to_string,
+        macros::error_spanned
+    },
+    params::{
+        Mutate, macros::*
+    }
+};
+
+// Skipped tests pass, as libtest has no runtime-skip status - bar those expected to panic,
+// which would then fail, so panic as expected instead
+pub(crate) fn skip_test(target: &ItemFn) -> Stmt {
+    let Some(attr) = target.attrs.iter().find(| attr | attribute_name_to_string(attr) == "should_panic") else {
+        return syn::parse_quote!(return ::sith_runtime::skip::Skipped::skipped(););
+    };
+
+    let as_str = | expr: &Expr | match expr {
+        Expr::Lit(ExprLit { lit: Lit::Str(expected), .. }) => Some(expected.clone()),
+        _ => None
+    };
+    let expected: Option<LitStr> = match &attr.meta {
+        Meta::NameValue(meta) => as_str(&meta.value),
+        Meta::List(meta) => meta.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated).ok()
+            .and_then(| nested | nested.iter().find_map(| meta | match meta {
+                Meta::NameValue(meta) if meta.path.is_ident("expected") => as_str(&meta.value),
+                _ => None
+            })),
+        Meta::Path(_) => None
+    };
+
+    match expected {
+        Some(expected) => syn::parse_quote!(::core::panic!("skipped, as expected to panic with: {}", #expected);),
+        None => syn::parse_quote!(::core::panic!("skipped, as expected to panic");)
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct ParamSkipIf {
+    pub condition: Expr,
+    pub reason: Option<LitStr>
+}
+
+impl Parse for ParamSkipIf {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let condition = input.parse::<Expr>()?;
+        if input.is_empty() {
+            return Ok(Self { condition, reason: None });
+        }
+
+        input.parse::<Token![,]>()?;
+        Ok(Self { condition, reason: Some(input.parse::<LitStr>()?) })
+    }
+}
+
+impl ParamSkipIf {
+    pub const IDENT: &'static str = "skip_if";
+}
+
+impl Mutate for ParamSkipIf {
+    type Item = ItemFn;
+
+    // Checked ahead of all other statements, so nothing is set up for a skipped test
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        let name: String = target.sig.ident.unraw().to_string();
+        let condition: &Expr = &self.condition;
+        let reason: String = match &self.reason {
+            Some(reason) => reason.value(),
+            None => format!("`{}`", condition.to_token_stream())
+        };
+
+        let skip: Stmt = skip_test(target);
+
+        target.block.stmts.insert(0, syn::parse_quote!(
+            if ::sith_runtime::skip::skip_if(#name, #condition, #reason) {
+                #skip
+            }
+        ));
+
+        Ok(())
+    }
+}
+
+impl ToTokens for ParamSkipIf {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.condition.to_tokens(tokens);
+        if let Some(reason) = &self.reason {
+            quote::quote!(, #reason).to_tokens(tokens);
+        }
+    }
+}
+
+impl_unique!(ParamSkipIf);
+
+impl core::fmt::Debug for ParamSkipIf {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ParamSkipIf")
+            .field(&self.to_token_stream().to_string())
+            .finish()
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct ParamRequiresEnv(pub Punctuated<LitStr, Token![,]>);
+
+impl Parse for ParamRequiresEnv {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let names = Punctuated::<LitStr, Token![,]>::parse_terminated(input)?;
+        if names.is_empty() {
+            return Err(error_spanned!("requires_env(): expected the name of an environment variable", &input.span()));
+        }
+
+        Ok(Self(names))
+    }
+}
+
+impl ParamRequiresEnv {
+    pub const IDENT: &'static str = "requires_env";
+}
+
+impl Mutate for ParamRequiresEnv {
+    type Item = ItemFn;
+
+    // As with `skip_if`, checked ahead of all other statements
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        let name: String = target.sig.ident.unraw().to_string();
+        let names = self.0.iter();
+
+        let skip: Stmt = skip_test(target);
+
+        target.block.stmts.insert(0, syn::parse_quote!(
+            if ::sith_runtime::skip::requires_env(#name, &[#(#names),*]) {
+                #skip
+            }
+        ));
+
+        Ok(())
+    }
+}
+
+impl_unique!(ParamRequiresEnv);
+impl_param!(ParamRequiresEnv, 0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::quote;
+    use syn::parse_quote;
+
+    #[test]
+    fn skip_if_parse_accepts_optional_reason() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamSkipIf>(quote!(cfg!(windows))),
+            Ok(quote!(cfg!(windows)))
+        );
+        assert_eq_parsed!(
+            syn::parse2::<ParamSkipIf>(quote!(!fixtures().exists(), "no fixtures")),
+            Ok(quote!(!fixtures().exists(), "no fixtures"))
+        );
+    }
+
+    #[test]
+    fn skip_if_mutate_returns_early_when_condition_holds() {
+        let mut target: ItemFn = parse_quote!{
+            fn loads_fixtures() {
+                body();
+            }
+        };
+
+        let param: ParamSkipIf = parse_quote!(!fixtures().exists(), "no fixtures");
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            fn loads_fixtures() {
+                if ::sith_runtime::skip::skip_if("loads_fixtures", !fixtures().exists(), "no fixtures") {
+                    return ::sith_runtime::skip::Skipped::skipped();
+                }
+                body();
+            }
+        });
+    }
+
+    #[test]
+    fn skip_if_mutate_defaults_reason_to_condition() {
+        let mut target: ItemFn = parse_quote!(fn test() {});
+
+        let param: ParamSkipIf = parse_quote!(is_ci);
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            fn test() {
+                if ::sith_runtime::skip::skip_if("test", is_ci, "`is_ci`") {
+                    return ::sith_runtime::skip::Skipped::skipped();
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn skip_if_mutate_panics_as_expected_by_should_panic() {
+        let mut target: ItemFn = parse_quote!{
+            #[should_panic(expected = "no fixtures")]
+            fn loads_fixtures() {}
+        };
+
+        let param: ParamSkipIf = parse_quote!(is_ci);
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            #[should_panic(expected = "no fixtures")]
+            fn loads_fixtures() {
+                if ::sith_runtime::skip::skip_if("loads_fixtures", is_ci, "`is_ci`") {
+                    ::core::panic!("skipped, as expected to panic with: {}", "no fixtures");
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn skip_test_accepts_all_should_panic_forms() {
+        assert_eq_tokens!(
+            skip_test(&parse_quote!(#[should_panic] fn test() {})),
+            quote!(::core::panic!("skipped, as expected to panic");)
+        );
+        assert_eq_tokens!(
+            skip_test(&parse_quote!(#[should_panic = "overflow"] fn test() {})),
+            quote!(::core::panic!("skipped, as expected to panic with: {}", "overflow");)
+        );
+        assert_eq_tokens!(
+            skip_test(&parse_quote!(#[inline] fn test() {})),
+            quote!(return ::sith_runtime::skip::Skipped::skipped();)
+        );
+    }
+
+    #[test]
+    fn requires_env_parse_returns_error_without_names() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamRequiresEnv>(quote!()),
+            Err(error_spanned!("requires_env(): expected the name of an environment variable"))
+        );
+    }
+
+    #[test]
+    fn requires_env_mutate_returns_early_when_unset() {
+        let mut target: ItemFn = parse_quote!{
+            fn loads_fixtures() {
+                body();
+            }
+        };
+
+        let param: ParamRequiresEnv = parse_quote!("FIXTURES_DIR", "DATABASE_URL");
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            fn loads_fixtures() {
+                if ::sith_runtime::skip::requires_env("loads_fixtures", &["FIXTURES_DIR", "DATABASE_URL"]) {
+                    return ::sith_runtime::skip::Skipped::skipped();
+                }
+                body();
+            }
+        });
+    }
+}
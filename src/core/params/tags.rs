@@ -0,0 +1,186 @@
+use proc_macro2::TokenStream;
+use quote::{
+    format_ident, ToTokens
+};
+
+use syn::{
+    Ident, ItemFn, Stmt, Token, Result,
+    ext::IdentExt,
+    punctuated::Punctuated,
+    parse::{
+        Parse, ParseStream
+    }
+};
+use crate::{
+    common::macros::error_spanned,
+    params::{
+        Mutate, macros::*,
+        skip::skip_test
+    }
+};
+
+#[derive(Clone, Default)]
+pub(crate) struct ParamTags(pub Vec<Ident>);
+
+impl Parse for ParamTags {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let tags = Punctuated::<Ident, Token![,]>::parse_terminated(input)?;
+        if tags.is_empty() {
+            return Err(error_spanned!("tags(): expected at least one tag, i.e. `tags(slow)`", &input.span()));
+        }
+
+        Ok(Self(tags.into_iter().collect()))
+    }
+}
+
+impl ParamTags {
+    pub const IDENT: &'static str = "tags";
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    // Tags of both, those of `self` first
+    pub fn merge(&self, other: &ParamTags) -> ParamTags {
+        let mut tags: Vec<Ident> = self.0.clone();
+        for tag in &other.0 {
+            if !tags.contains(tag) {
+                tags.push(tag.clone());
+            }
+        }
+
+        ParamTags(tags)
+    }
+
+    // Prefixes the test's name by `tag_<tag>__` per tag, so tests may be filtered by name alone
+    pub fn prefix(&self, target: &mut ItemFn) {
+        if self.is_empty() {
+            return;
+        }
+
+        for tag in self.0.iter().rev() {
+            target.sig.ident = format_ident!("tag_{}__{}", tag.unraw(), target.sig.ident);
+        }
+
+        // The double underscore separating tags isn't snake case
+        target.attrs.push(syn::parse_quote!(#[allow(non_snake_case)]));
+    }
+}
+
+impl Mutate for ParamTags {
+    type Item = ItemFn;
+
+    // Filters the test by its tags (if any), ahead of all other statements - applied solely under
+    // the `runtime-filter` feature, so plain tests needn't reference `sith_runtime`
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        let name: String = target.sig.ident.unraw().to_string();
+        let tags = self.0.iter().map(| tag | tag.unraw().to_string());
+        let skip: Stmt = skip_test(target);
+
+        target.block.stmts.insert(0, syn::parse_quote!(
+            if ::sith_runtime::filter::is_filtered(::core::module_path!(), #name, &[#(#tags),*]) {
+                #skip
+            }
+        ));
+
+        Ok(())
+    }
+}
+
+impl ToTokens for ParamTags {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let tags = self.0.iter();
+        quote::quote!(#(#tags),*).to_tokens(tokens);
+    }
+}
+
+impl_unique!(ParamTags);
+
+impl core::fmt::Debug for ParamTags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ParamTags")
+            .field(&self.to_token_stream().to_string())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::quote;
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_accepts_tags() {
+        assert_eq_parsed!(syn::parse2::<ParamTags>(quote!(slow, io)), Ok(quote!(slow, io)));
+    }
+
+    #[test]
+    fn parse_returns_error_without_tags() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamTags>(quote!()),
+            Err(error_spanned!("tags(): expected at least one tag, i.e. `tags(slow)`"))
+        );
+    }
+
+    #[test]
+    fn merge_deduplicates_tags() {
+        let suite: ParamTags = parse_quote!(db, slow);
+        assert_eq_tokens!(suite.merge(&parse_quote!(slow, io)), quote!(db, slow, io));
+    }
+
+    #[test]
+    fn prefix_prepends_each_tag_in_order() {
+        let mut target: ItemFn = parse_quote!(fn fetches() {});
+
+        let param: ParamTags = parse_quote!(slow, io);
+        param.prefix(&mut target);
+        assert_eq_tokens!(target, quote!{
+            #[allow(non_snake_case)]
+            fn tag_slow__tag_io__fetches() {}
+        });
+    }
+
+    #[test]
+    fn mutate_filters_test_by_tags() {
+        let mut target: ItemFn = parse_quote!{
+            fn fetches() {
+                body();
+            }
+        };
+
+        let param: ParamTags = parse_quote!(slow, io);
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            fn fetches() {
+                if ::sith_runtime::filter::is_filtered(::core::module_path!(), "fetches", &["slow", "io"]) {
+                    return ::sith_runtime::skip::Skipped::skipped();
+                }
+                body();
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_panics_as_expected_by_should_panic_when_filtered() {
+        let mut target: ItemFn = parse_quote!{
+            #[should_panic(expected = "overflow")]
+            fn overflows() {}
+        };
+
+        assert_eq_mutate!(ParamTags::default(), &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            #[should_panic(expected = "overflow")]
+            fn overflows() {
+                if ::sith_runtime::filter::is_filtered(::core::module_path!(), "overflows", &[]) {
+                    ::core::panic!("skipped, as expected to panic with: {}", "overflow");
+                }
+            }
+        });
+    }
+}
@@ -1,5 +1,8 @@
 use syn::{
-    Stmt, Block, Result
+    Stmt, Block, Result,
+    parse::{
+        Parse, ParseStream
+    }
 };
 use crate::params::{
     Mutate, macros::*
@@ -8,11 +11,29 @@ use crate::params::{
 #[derive(Clone)]
 pub struct ParamTeardown(pub Vec<Stmt>);
 
+impl Parse for ParamTeardown {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self(Block::parse_within(input)?))
+    }
+}
+
 impl Mutate for ParamTeardown {
     type Item = Block;
 
+    // Any trailing expression (i.e. the output of a shared `repeat`) is bound ahead of the
+    // teardown, then returned after it
     fn mutate(&self, target: &mut Self::Item) -> Result<()> {
-        target.stmts.extend(self.0.clone());
+        match target.stmts.pop() {
+            Some(Stmt::Expr(output, None)) => {
+                target.stmts.push(syn::parse_quote!(let __sith_output = #output;));
+                target.stmts.extend(self.0.clone());
+                target.stmts.push(Stmt::Expr(syn::parse_quote!(__sith_output), None));
+            },
+            last => {
+                target.stmts.extend(last);
+                target.stmts.extend(self.0.clone());
+            }
+        }
 
         Ok(())
     }
@@ -85,6 +106,25 @@ mod tests {
         });
     }
 
+    #[test]
+    fn mutate_returns_trailing_expression_after_statements() {
+        let mut target: Block = syn::parse2(quote!({ let a = 1; run(a) })).unwrap();
+
+        assert!(ParamTeardown(Vec::from([syn::parse2::<Stmt>(quote!(drop(a);)).unwrap()])).mutate(&mut target).is_ok());
+        assert_eq!(
+            target.to_token_stream().to_string(),
+            quote!({ let a = 1; let __sith_output = run(a); drop(a); __sith_output }).to_string()
+        );
+    }
+
+    #[test]
+    fn parse_accepts_statements() {
+        let param: ParamTeardown = syn::parse2(quote!(let a = 1; foo();)).unwrap();
+
+        assert_eq!(param.0.len(), 2);
+        assert_eq!(param.to_token_stream().to_string(), quote!(let a = 1; foo();).to_string());
+    }
+
     #[test]
     fn parameter_is_unique() {
         let first = ParamTeardown(Vec::new());
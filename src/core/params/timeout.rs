@@ -0,0 +1,159 @@
+use core::time::Duration;
+
+use syn::{
+    LitStr, ItemFn, Result,
+    ext::IdentExt,
+    parse::{
+        Parse, ParseStream
+    }
+};
+use crate::{
+    common::macros::error_spanned,
+    params::{
+        Mutate, macros::*
+    }
+};
+
+#[derive(Clone)]
+pub(crate) struct ParamTimeout(pub LitStr, Duration);
+
+impl Parse for ParamTimeout {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let literal = input.parse::<LitStr>()?;
+        let Some(duration) = parse_duration(&literal.value()) else {
+            return Err(error_spanned!("timeout: expected a duration, i.e. \"2s\" or \"500ms\"", &literal));
+        };
+
+        Ok(Self(literal, duration))
+    }
+}
+
+impl ParamTimeout {
+    pub const IDENT: &'static str = "timeout";
+}
+
+impl Mutate for ParamTimeout {
+    type Item = ItemFn;
+
+    // Sync tests are run on a watchdog thread, whereas async tests are raced against the
+    // deadline - so are cancelled upon such. Either way, the test can't borrow from outside
+    // of its body, so must apply after all else bar the seed (which is `Copy`)
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        let name: String = target.sig.ident.unraw().to_string();
+        let nanos: u64 = self.1.as_nanos() as u64;
+        let stmts = core::mem::take(&mut target.block.stmts);
+
+        *target.block = match target.sig.asyncness {
+            Some(_) => syn::parse_quote!({
+                ::sith_runtime::timeout::Timeout::new(
+                    #name, ::core::time::Duration::from_nanos(#nanos),
+                    async move { #(#stmts)* }
+                ).await
+            }),
+            None => syn::parse_quote!({
+                ::sith_runtime::timeout::run(
+                    #name, ::core::time::Duration::from_nanos(#nanos),
+                    move || { #(#stmts)* }
+                )
+            })
+        };
+
+        Ok(())
+    }
+}
+
+impl_unique!(ParamTimeout);
+impl_param!(ParamTimeout, 0);
+
+// Parses durations of the form `<number><unit>`, i.e. "2s", "1.5s" or "500ms"
+fn parse_duration(value: &str) -> Option<Duration> {
+    let split: usize = value.find(| c: char | !c.is_ascii_digit() && c != '.')?;
+    let (number, unit) = value.split_at(split);
+
+    let seconds: f64 = match unit.trim() {
+        "ns" => 1e-9,
+        "us" => 1e-6,
+        "ms" => 1e-3,
+        "s" => 1.0,
+        "m" => 60.0,
+        "h" => 3600.0,
+        _ => return None
+    };
+
+    let number: f64 = number.parse::<f64>().ok()?;
+    Duration::try_from_secs_f64(number * seconds).ok().filter(| duration | !duration.is_zero())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::{
+        quote, ToTokens
+    };
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_accepts_durations() {
+        assert_eq!(parse_duration("2s"), Some(Duration::from_secs(2)));
+        assert_eq!(parse_duration("1.5s"), Some(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("500ms"), Some(Duration::from_millis(500)));
+        assert_eq!(parse_duration("10us"), Some(Duration::from_micros(10)));
+        assert_eq!(parse_duration("2m"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1h"), Some(Duration::from_secs(3600)));
+    }
+
+    #[test]
+    fn parse_returns_error_on_invalid_durations() {
+        for duration in ["2", "s", "2 days", "0s", "-1s", ""] {
+            assert_eq_parsed!(
+                syn::parse2::<ParamTimeout>(quote!(#duration)),
+                Err(error_spanned!("timeout: expected a duration, i.e. \"2s\" or \"500ms\""))
+            );
+        }
+    }
+
+    #[test]
+    fn mutate_runs_sync_tests_on_watchdog() {
+        let mut target: ItemFn = parse_quote!{
+            fn my_test() {
+                body();
+            }
+        };
+
+        let param: ParamTimeout = parse_quote!("2s");
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            fn my_test() {
+                ::sith_runtime::timeout::run(
+                    "my_test", ::core::time::Duration::from_nanos(2000000000u64),
+                    move | | { body(); }
+                )
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_races_async_tests_against_deadline() {
+        let mut target: ItemFn = parse_quote!{
+            async fn my_test() {
+                body().await;
+            }
+        };
+
+        let param: ParamTimeout = parse_quote!("500ms");
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            async fn my_test() {
+                ::sith_runtime::timeout::Timeout::new(
+                    "my_test", ::core::time::Duration::from_nanos(500000000u64),
+                    async move { body().await; }
+                ).await
+            }
+        });
+    }
+}
@@ -0,0 +1,281 @@
+use quote::ToTokens;
+
+use proc_macro2::TokenStream;
+use syn::{
+    Ident, Type, Expr,
+    ItemFn, GenericParam, Generics,
+    WherePredicate, TypeParamBound, TraitBoundModifier,
+    Token, Result,
+    punctuated::Punctuated,
+    parse::{
+        Parse, ParseStream
+    },
+    buffer::TokenBuffer,
+    token::Brace,
+    Lit
+};
+use crate::{
+    common::{
+        greedy_parse_with_delim,
+        recursive_descent_replace,
+        macros::error_spanned
+    },
+    params::{
+        Mutate, macros::*
+    }
+};
+
+#[derive(Clone)]
+pub(crate) enum GenericValue {
+    Type(Type),
+    Const(Expr)
+}
+
+impl Parse for GenericValue {
+    fn parse(input: ParseStream) -> Result<Self> {
+        // Mirrors rustc's handling of generic args: literals and blocks are const values
+        if input.peek(Lit) || input.peek(Brace) || input.peek(Token![-]) {
+            return Ok(GenericValue::Const(input.parse::<Expr>()?));
+        }
+
+        Ok(GenericValue::Type(input.parse::<Type>()?))
+    }
+}
+
+impl ToTokens for GenericValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            GenericValue::Type(ty) => ty.to_tokens(tokens),
+            GenericValue::Const(expr) => expr.to_tokens(tokens)
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct GenericAssignment(pub Ident, pub GenericValue);
+
+impl Parse for GenericAssignment {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let Result::Ok(ident) = input.parse::<Ident>() else {
+            return Err(error_spanned!("expected generic parameter", &input.span()));
+        };
+
+        input.parse::<Token![=]>()?;
+        Ok(Self(ident, input.parse::<GenericValue>()?))
+    }
+}
+
+impl ToTokens for GenericAssignment {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (ident, value) = (&self.0, &self.1);
+        quote::quote!(#ident = #value).to_tokens(tokens);
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct ParamTypes(pub Vec<GenericAssignment>);
+
+impl Parse for ParamTypes {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self(
+            greedy_parse_with_delim::<GenericAssignment, Token![,]>(input)?
+        ))
+    }
+}
+
+impl Mutate for ParamTypes {
+    type Item = ItemFn;
+
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        for GenericAssignment(ident, _) in &self.0 {
+            let Some(position) = target.sig.generics.params.iter().position(| param | match param {
+                GenericParam::Type(ty) => ty.ident.eq(ident),
+                GenericParam::Const(constant) => constant.ident.eq(ident),
+                GenericParam::Lifetime(_) => false
+            }) else {
+                return Err(error_spanned!(format!("types(): no generic parameter `{}`", ident), ident));
+            };
+
+            let mut params: Vec<GenericParam> = core::mem::take(&mut target.sig.generics.params).into_iter().collect();
+            let removed = params.remove(position);
+            target.sig.generics.params = params.into_iter().collect();
+
+            // Retain the bounds of the instantiated parameter as a where predicate,
+            // post-substitution these become i.e `where u8: Codec`, which rustc
+            // still checks despite the test function no longer being generic
+            if let GenericParam::Type(ty) = removed {
+                let bounds = ty.bounds.into_iter().filter(| bound | !is_relaxed(bound)).collect::<Vec<_>>();
+                if !bounds.is_empty() {
+                    target.sig.generics.make_where_clause().predicates.push(
+                        syn::parse2::<WherePredicate>(quote::quote!(#ident: #(#bounds)+*))?
+                    );
+                }
+            }
+
+            remove_relaxed_predicates(&mut target.sig.generics, ident);
+        }
+
+        if target.sig.generics.params.is_empty() {
+            target.sig.generics.lt_token = None;
+            target.sig.generics.gt_token = None;
+        }
+
+        let mut tokens: TokenStream = target.to_token_stream();
+        for GenericAssignment(ident, value) in &self.0 {
+            tokens = recursive_descent_replace(
+                &mut TokenBuffer::new2(tokens).begin(),
+                ident,
+                &value.to_token_stream()
+            );
+        }
+
+        *target = syn::parse2::<ItemFn>(tokens)?;
+        Ok(())
+    }
+}
+
+// Relaxed bounds, i.e `?Sized`, are only permitted upon generic parameters - not concrete types
+fn is_relaxed(bound: &TypeParamBound) -> bool {
+    matches!(bound, TypeParamBound::Trait(bound) if matches!(bound.modifier, TraitBoundModifier::Maybe(_)))
+}
+
+fn remove_relaxed_predicates(generics: &mut Generics, ident: &Ident) {
+    let Some(where_clause) = &mut generics.where_clause else {
+        return;
+    };
+
+    where_clause.predicates = core::mem::take(&mut where_clause.predicates).into_iter().filter_map(| predicate | {
+        let WherePredicate::Type(mut predicate) = predicate else {
+            return Some(predicate);
+        };
+
+        if matches!(&predicate.bounded_ty, Type::Path(ty) if ty.qself.is_none() && ty.path.is_ident(ident)) {
+            predicate.bounds = predicate.bounds.into_iter().filter(| bound | !is_relaxed(bound)).collect::<Punctuated<_, _>>();
+        }
+
+        (!predicate.bounds.is_empty()).then_some(WherePredicate::Type(predicate))
+    }).collect();
+
+    if where_clause.predicates.is_empty() {
+        generics.where_clause = None;
+    }
+}
+
+impl_unique!(ParamTypes);
+impl_param!(ParamTypes, iterable(0));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::quote;
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_accepts_types() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamTypes>(quote!(T = Vec<u8>, U = &'static str)),
+            Ok(quote!(T = Vec<u8> U = &'static str))
+        );
+    }
+
+    #[test]
+    fn parse_accepts_consts() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamTypes>(quote!(N = 16, M = { 4 * 4 }, O = -1)),
+            Ok(quote!(N = 16 M = { 4 * 4 } O = -1))
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_on_missing_generic_parameter() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamTypes>(quote!(= u8)),
+            Err(error_spanned!("expected generic parameter"))
+        );
+    }
+
+    #[test]
+    fn mutate_instantiates_type_parameters() {
+        let mut target: ItemFn = parse_quote!{
+            fn roundtrip<T: Codec>(input: T) -> T {
+                T::decode(input.encode())
+            }
+        };
+
+        let param: ParamTypes = parse_quote!(T = u8);
+        assert_eq_mutate!(param, &mut target, Ok(()));
+
+        assert_eq_tokens!(target, quote!{
+            fn roundtrip(input: u8) -> u8 where u8: Codec {
+                u8::decode(input.encode())
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_drops_relaxed_bounds() {
+        let mut target: ItemFn = parse_quote!{
+            fn measure<T: ?Sized + Measure, U>(value: &T, other: &U) where U: ?Sized {
+                T::measure(value);
+            }
+        };
+
+        let param: ParamTypes = parse_quote!(T = str, U = [u8]);
+        assert_eq_mutate!(param, &mut target, Ok(()));
+
+        assert_eq_tokens!(target, quote!{
+            fn measure(value: &str, other: &[u8]) where str: Measure {
+                str::measure(value);
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_instantiates_const_parameters() {
+        let mut target: ItemFn = parse_quote!{
+            fn buffer<'a, const N: usize>() {
+                let buf = [0u8; N];
+            }
+        };
+
+        let param: ParamTypes = parse_quote!(N = 16);
+        assert_eq_mutate!(param, &mut target, Ok(()));
+
+        assert_eq_tokens!(target, quote!{
+            fn buffer<'a>() {
+                let buf = [0u8; 16];
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_retains_existing_where_predicates() {
+        let mut target: ItemFn = parse_quote!{
+            fn roundtrip<T, U>() where T: Into<U> {}
+        };
+
+        let param: ParamTypes = parse_quote!(T = u8, U = u16);
+        assert_eq_mutate!(param, &mut target, Ok(()));
+
+        assert_eq_tokens!(target, quote!{
+            fn roundtrip() where u8: Into<u16> {}
+        });
+    }
+
+    #[test]
+    fn mutate_returns_error_on_unknown_generic_parameter() {
+        let mut target: ItemFn = parse_quote!{
+            fn roundtrip<T>() {}
+        };
+
+        assert_eq_mutate!(
+            ParamTypes(vec![parse_quote!(U = u8)]), &mut target,
+            Err(error_spanned!("types(): no generic parameter `U`"))
+        );
+    }
+}
@@ -2,20 +2,26 @@ use proc_macro2::TokenStream;
 
 use syn::{
     ItemFn, Expr, Stmt,
-    Pat, Result,
+    Pat, Type, Result,
+    Generics, GenericParam,
+    GenericArgument, PathArguments,
     parse::{
         Parse, ParseStream
     },
     token::Mut
 };
 use quote::{
+    format_ident,
     ToTokens, TokenStreamExt
 };
 use super::{
     ParamWithInner, split_rust_fn_input
 };
 use crate::{
-    common::macros::error_spanned,
+    common::{
+        tokens_contain_ident,
+        macros::error_spanned
+    },
     params::{
         Mutate, macros::*
     }
@@ -39,16 +45,15 @@ impl Mutate for ParamAssignment {
     type Item = ItemFn;
 
     fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        let generics: Generics = target.sig.generics.clone();
         let mut fn_input = target.sig.inputs.pop();
-        let (attrs, Pat::Ident(def), ty) = split_rust_fn_input(fn_input.as_mut())? else {
-            // https://doc.rust-lang.org/reference/items/functions.html
-            // Sig may only contain: (SelfParam[0..1], FunctionParam[0..n])
-            unreachable!("Rust syntax should not allow any `Pat` but `Pat::Ident` in fn inputs");
-        };
+        let (attrs, def, ty) = split_rust_fn_input(fn_input.as_mut())?;
 
-        // If mut override is present, ensure it's set
-        if self.0.is_some() {
-            def.mutability = self.0;
+        // If mut override is present, ensure it's set on each binding
+        if let Some(mutability) = self.0 {
+            if !apply_mut_override(def, mutability) {
+                return Err(error_spanned!("`mut` has no bindings to apply to", &mutability));
+            }
         }
 
         let mut tokens = TokenStream::new();
@@ -56,13 +61,163 @@ impl Mutate for ParamAssignment {
         tokens.append_all(attrs);
 
         let expr: &Expr = &self.1;
-        quote::quote!(let #def: #ty = #expr;).to_tokens(&mut tokens);
+        match render_input_helper(ty, &generics) {
+            Some(helper) => quote::quote!(let #def = { #helper __sith_input(#expr) };),
+            None => quote::quote!(let #def: #ty = #expr;)
+        }.to_tokens(&mut tokens);
 
         target.block.stmts.insert(0, syn::parse2::<Stmt>(tokens)?);
         Ok(())
     }
 }
 
+// Inputs may be any irrefutable pattern, i.e `(a, b): (u8, u8)` - in which
+// case, `mut` applies to every by-value binding within the pattern
+fn apply_mut_override(pat: &mut Pat, mutability: Mut) -> bool {
+    match pat {
+        Pat::Ident(ident) => {
+            if ident.by_ref.is_none() {
+                ident.mutability = Some(mutability);
+            }
+
+            let applied_to_subpat = ident.subpat.as_mut().is_some_and(| (_, subpat) | {
+                apply_mut_override(subpat, mutability)
+            });
+
+            ident.by_ref.is_none() || applied_to_subpat
+        },
+        Pat::Paren(inner) => apply_mut_override(&mut inner.pat, mutability),
+        Pat::Reference(inner) => apply_mut_override(&mut inner.pat, mutability),
+        Pat::Type(inner) => apply_mut_override(&mut inner.pat, mutability),
+        Pat::Tuple(inner) => apply_mut_override_all(inner.elems.iter_mut(), mutability),
+        Pat::TupleStruct(inner) => apply_mut_override_all(inner.elems.iter_mut(), mutability),
+        Pat::Slice(inner) => apply_mut_override_all(inner.elems.iter_mut(), mutability),
+        Pat::Struct(inner) => apply_mut_override_all(
+            inner.fields.iter_mut().map(| field | &mut *field.pat), mutability
+        ),
+        _ => false
+    }
+}
+
+fn apply_mut_override_all<'a>(pats: impl Iterator<Item = &'a mut Pat>, mutability: Mut) -> bool {
+    // Must not short-circuit - every binding should be visited
+    let mut applied = false;
+    for pat in pats {
+        applied |= apply_mut_override(pat, mutability);
+    }
+
+    applied
+}
+
+// `let` statements can't be annotated with `impl Trait`, nor with the generics of the
+// test function - such inputs are instead passed through an identity function declaring
+// equivalent generics, so trait bounds remain checked whilst generic types are inferred.
+// `impl Trait` is kept in return position, so such inputs are opaque as they would be in
+// a regular function
+fn render_input_helper(ty: &Type, generics: &Generics) -> Option<TokenStream> {
+    let mut helper_ty: Type = ty.clone();
+    let mut helper_params: Vec<GenericParam> = Vec::new();
+    replace_impl_traits(&mut helper_ty, &mut helper_params);
+
+    // Unused lifetimes are permitted, so may be declared regardless
+    let mut lifetimes: Vec<&GenericParam> = Vec::new();
+    let mut excluded: Vec<&syn::Ident> = Vec::new();
+    for param in &generics.params {
+        let ident = match param {
+            GenericParam::Type(param) => &param.ident,
+            GenericParam::Const(param) => &param.ident,
+            GenericParam::Lifetime(_) => {
+                lifetimes.push(param);
+                continue;
+            }
+        };
+
+        if tokens_contain_ident(ty.to_token_stream(), ident) {
+            helper_params.push(param.clone());
+        } else {
+            excluded.push(ident);
+        }
+    }
+
+    if helper_params.is_empty() {
+        return None;
+    }
+
+    // Bounds relating to other generics, i.e `T: Into<U>`, can't be inferred from this input
+    // alone - such are instead checked once every input is bound, see `render_inputs_check`
+    for param in &mut helper_params {
+        if let GenericParam::Type(param) = param {
+            param.bounds = core::mem::take(&mut param.bounds).into_iter().filter(| bound | {
+                !excluded.iter().any(| ident | tokens_contain_ident(bound.to_token_stream(), ident))
+            }).collect();
+        }
+    }
+
+    let predicates = generics.where_clause.iter()
+        .flat_map(| where_clause | where_clause.predicates.iter())
+        .filter(| predicate | {
+            !excluded.iter().any(| ident | tokens_contain_ident(predicate.to_token_stream(), ident))
+        });
+
+    Some(quote::quote!{
+        fn __sith_input<#(#lifetimes,)* #(#helper_params),*>(input: #helper_ty) -> #ty where #(#predicates,)* {
+            input
+        }
+    })
+}
+
+fn replace_impl_traits(ty: &mut Type, params: &mut Vec<GenericParam>) {
+    match ty {
+        Type::ImplTrait(impl_trait) => {
+            let ident = format_ident!("__SithImpl{}", params.len());
+            let bounds = &impl_trait.bounds;
+            params.push(syn::parse_quote!(#ident: #bounds));
+
+            *ty = syn::parse_quote!(#ident);
+        },
+        Type::Reference(inner) => replace_impl_traits(&mut inner.elem, params),
+        Type::Ptr(inner) => replace_impl_traits(&mut inner.elem, params),
+        Type::Slice(inner) => replace_impl_traits(&mut inner.elem, params),
+        Type::Array(inner) => replace_impl_traits(&mut inner.elem, params),
+        Type::Paren(inner) => replace_impl_traits(&mut inner.elem, params),
+        Type::Group(inner) => replace_impl_traits(&mut inner.elem, params),
+        Type::Tuple(inner) => {
+            inner.elems.iter_mut().for_each(| elem | replace_impl_traits(elem, params));
+        },
+        Type::Path(inner) => {
+            for segment in &mut inner.path.segments {
+                let PathArguments::AngleBracketed(args) = &mut segment.arguments else {
+                    continue;
+                };
+
+                args.args.iter_mut().for_each(| arg | {
+                    if let GenericArgument::Type(elem) = arg {
+                        replace_impl_traits(elem, params);
+                    }
+                });
+            }
+        },
+        _ => {}
+    }
+}
+
+impl ParamAssignment {
+    // Within `property(...)`, inputs are generated from strategies rather than bound directly
+    pub fn strategy(&self) -> Result<Expr> {
+        if let Some(mutability) = self.0 {
+            return Err(error_spanned!("property(): `mut` can't be applied to strategies, declare the input `mut` instead", &mutability));
+        }
+
+        Ok(self.1.clone())
+    }
+}
+
+impl From<Expr> for ParamAssignment {
+    fn from(value: Expr) -> Self {
+        Self(None, value)
+    }
+}
+
 impl From<ParamAssignment> for ParamWithInner {
     fn from(value: ParamAssignment) -> Self {
         ParamWithInner::Assignment(value)
@@ -254,6 +409,138 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mutate_binds_impl_trait_inputs_through_helper() {
+        let mut target: ItemFn = parse_quote!{
+            fn input(input: &impl Display) {}
+        };
+
+        assert_eq_mutate!(ParamAssignment(None, parse_quote!(&5)), &mut target, Ok(()));
+
+        assert_eq_tokens!(
+            target.block.stmts[0],
+            quote!{
+                let input = {
+                    fn __sith_input<__SithImpl0: Display>(input: &__SithImpl0) -> &impl Display {
+                        input
+                    }
+                    __sith_input(&5)
+                };
+            }
+        );
+    }
+
+    #[test]
+    fn mutate_omits_bounds_relating_to_other_generics() {
+        let mut target: ItemFn = parse_quote!{
+            fn input<T: Into<U> + Copy, U>(input: T) where T: PartialEq<U> {}
+        };
+
+        assert_eq_mutate!(ParamAssignment(None, parse_quote!(5u8)), &mut target, Ok(()));
+
+        assert_eq_tokens!(
+            target.block.stmts[0],
+            quote!{
+                let input = {
+                    fn __sith_input<T: Copy>(input: T) -> T {
+                        input
+                    }
+                    __sith_input(5u8)
+                };
+            }
+        );
+    }
+
+    #[test]
+    fn mutate_binds_generic_inputs_through_helper() {
+        let mut target: ItemFn = parse_quote!{
+            fn input<'a, T: Copy, U>(input: Vec<T>) where T: Ord, U: Ord {}
+        };
+
+        assert_eq_mutate!(ParamAssignment(None, parse_quote!(vec![1])), &mut target, Ok(()));
+
+        assert_eq_tokens!(
+            target.block.stmts[0],
+            quote!{
+                let input = {
+                    fn __sith_input<'a, T: Copy>(input: Vec<T>) -> Vec<T> where T: Ord, {
+                        input
+                    }
+                    __sith_input(vec![1])
+                };
+            }
+        );
+    }
+
+    #[test]
+    fn mutate_accepts_tuple_patterns() {
+        let mut target: ItemFn = parse_quote!{
+            fn input((a, b): (u8, u8)) {}
+        };
+
+        assert_eq_mutate!(ParamAssignment(None, parse_quote!((1, 2))), &mut target, Ok(()));
+
+        assert_eq_tokens!(
+            target.block.stmts[0],
+            quote!(let (a, b): (u8, u8) = (1, 2);)
+        );
+    }
+
+    #[test]
+    fn mutate_accepts_struct_patterns() {
+        let mut target: ItemFn = parse_quote!{
+            fn input(Point { x, y: renamed }: Point) {}
+        };
+
+        assert_eq_mutate!(ParamAssignment(None, parse_quote!(Point { x: 1, y: 2 })), &mut target, Ok(()));
+
+        assert_eq_tokens!(
+            target.block.stmts[0],
+            quote!(let Point { x, y: renamed }: Point = Point { x: 1, y: 2 };)
+        );
+    }
+
+    #[test]
+    fn mutate_accepts_wildcard_patterns() {
+        let mut target: ItemFn = parse_quote!{
+            fn input(_: Marker) {}
+        };
+
+        assert_eq_mutate!(ParamAssignment(None, parse_quote!(Marker)), &mut target, Ok(()));
+
+        assert_eq_tokens!(
+            target.block.stmts[0],
+            quote!(let _: Marker = Marker;)
+        );
+    }
+
+    #[test]
+    fn mutate_propagates_mut_overrides_to_each_pattern_binding() {
+        let mut target: ItemFn = parse_quote!{
+            fn input((a, [b, ref c], Point { x, .. }): Input) {}
+        };
+
+        let param: ParamAssignment = ParamAssignment(parse_quote!(mut), parse_quote!(input()));
+        assert_eq_mutate!(param, &mut target, Ok(()));
+
+        assert_eq_tokens!(
+            target.block.stmts[0],
+            quote!(let (mut a, [mut b, ref c], Point { mut x, .. }): Input = input();)
+        );
+    }
+
+    #[test]
+    fn mutate_returns_error_when_mut_override_has_no_bindings() {
+        let mut target: ItemFn = parse_quote!{
+            fn input(_: Marker) {}
+        };
+
+        assert_eq_mutate!(
+            ParamAssignment(parse_quote!(mut), parse_quote!(Marker)), &mut target,
+            Err(error_spanned!("`mut` has no bindings to apply to"))
+        );
+    }
+
     #[test]
     fn mutate_returns_error_when_no_fn_inputs() {
         let mut target: ItemFn = parse_quote!{
@@ -0,0 +1,277 @@
+use quote::ToTokens;
+
+use proc_macro2::Delimiter;
+use syn::{
+    Ident, Expr, ExprLit,
+    Lit, LitInt, RangeLimits,
+    UnOp, Token, Result,
+    parse::{
+        Parse, ParseStream, Parser
+    },
+    spanned::Spanned
+};
+use super::ParamWithInner;
+use crate::{
+    common::{
+        greedy_parse_with_delim,
+        parse_group_with_delim,
+        tokens_to_snake_case,
+        macros::error_spanned
+    },
+    params::{
+        macros::*, parse_param_args
+    }
+};
+
+// Guards against typos such as `each(0..1000000)` generating an unbounded number of tests
+const MAX_RANGE_LEN: i128 = 1024;
+
+#[derive(Clone)]
+pub(crate) struct ParamEach(pub Vec<Expr>);
+
+impl Parse for ParamEach {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse::<Ident>()?;
+        if name != "each" {
+            return Err(error_spanned!("expected `each(...)`", &name));
+        }
+
+        Ok(Self(parse_elements("each", parse_param_args::<Expr>(input)?)?))
+    }
+}
+
+// Accepts a literal list `[...]` or a range bounded by integer literals, expanded at macro time
+pub(crate) fn parse_elements(param: &str, expr: Expr) -> Result<Vec<Expr>> {
+    let span = expr.span();
+    let elements: Vec<Expr> = match expr {
+        Expr::Array(array) => array.elems.into_iter().collect(),
+        Expr::Range(range) => {
+            let (Some(start), Some(end)) = (&range.start, &range.end) else {
+                return Err(error_spanned!(format!("{}(): ranges must be bounded", param), &range));
+            };
+
+            let (start, start_suffix) = parse_int_bound(param, start)?;
+            let (end, end_suffix) = parse_int_bound(param, end)?;
+            let end = match range.limits {
+                RangeLimits::Closed(_) => end,
+                RangeLimits::HalfOpen(_) => end - 1
+            };
+
+            if end - start >= MAX_RANGE_LEN {
+                return Err(error_spanned!(
+                    format!("{}(): ranges may span at most {} elements", param, MAX_RANGE_LEN),
+                    &range
+                ));
+            }
+
+            // Retain the literal's type suffix, if specified on either bound
+            let suffix = if start_suffix.is_empty() { end_suffix } else { start_suffix };
+            (start..=end).map(| value | {
+                let literal = LitInt::new(&format!("{}{}", value.unsigned_abs(), suffix), range.limits.span());
+                match value.is_negative() {
+                    true => syn::parse_quote!(-#literal),
+                    false => syn::parse_quote!(#literal)
+                }
+            }).collect()
+        },
+        expr => {
+            return Err(error_spanned!(format!("{}(): expected `[...]` or an integer literal range", param), &expr));
+        }
+    };
+
+    if elements.is_empty() {
+        return Err(error_spanned!(format!("{}(): expected at least one element", param), &span));
+    }
+
+    Ok(elements)
+}
+
+// Names are derived from each element, i.e `Color::Red` => `color_red`. Should any be
+// indistinguishable once snake cased, the element's index is used instead
+pub(crate) fn element_names(elements: &[Expr]) -> Vec<String> {
+    let names: Vec<String> = elements.iter()
+        .map(| element | tokens_to_snake_case(element.to_token_stream()))
+        .collect();
+
+    let is_distinct = names.iter().enumerate().all(| (i, name) | {
+        !name.is_empty() && !names[..i].contains(name)
+    });
+
+    match is_distinct {
+        true => names,
+        false => (0..names.len()).map(| i | i.to_string()).collect()
+    }
+}
+
+impl ParamEach {
+    pub fn names(&self) -> Vec<String> {
+        element_names(&self.0)
+    }
+}
+
+fn parse_int_bound(param: &str, bound: &Expr) -> Result<(i128, String)> {
+    match bound {
+        Expr::Lit(ExprLit { lit: Lit::Int(int), .. }) => {
+            Ok((int.base10_parse::<i128>()?, int.suffix().to_owned()))
+        },
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+            let (value, suffix) = parse_int_bound(param, &unary.expr)?;
+            Ok((-value, suffix))
+        },
+        _ => Err(error_spanned!(format!("{}(): range bounds must be integer literals", param), bound))
+    }
+}
+
+impl From<ParamEach> for ParamWithInner {
+    fn from(value: ParamEach) -> Self {
+        ParamWithInner::Each(value)
+    }
+}
+
+impl_param!(ParamEach, iterable(0));
+
+// Elements of each `each(...)` are paired up by position, rather than combined
+#[derive(Clone)]
+pub(crate) struct ParamZip(pub Vec<ParamEach>);
+
+impl Parse for ParamZip {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse::<Ident>()?;
+        let eaches: Vec<ParamEach> = greedy_parse_with_delim::<ParamEach, Token![,]>.parse2(
+            parse_group_with_delim(Delimiter::Parenthesis, input)?
+        )?;
+
+        let Some(first) = eaches.first() else {
+            return Err(error_spanned!("zip(): expected `each(...)`", &name));
+        };
+
+        if let Some(mismatched) = eaches.iter().find(| each | each.0.len() != first.0.len()) {
+            return Err(error_spanned!(
+                format!("zip(): each(...) lengths differ ({} vs {})", first.0.len(), mismatched.0.len()),
+                mismatched
+            ));
+        }
+
+        Ok(Self(eaches))
+    }
+}
+
+impl ParamZip {
+    pub fn len(&self) -> usize {
+        self.0.first().map_or(0, | each | each.0.len())
+    }
+}
+
+impl From<ParamZip> for ParamWithInner {
+    fn from(value: ParamZip) -> Self {
+        ParamWithInner::Zip(value)
+    }
+}
+
+impl_param!(ParamZip, iterable(0));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::tests::macros::*;
+
+    use quote::quote;
+
+    #[test]
+    fn parse_accepts_arrays() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamEach>(quote!(each([Red, Green, Color::Blue]))),
+            Ok(quote!(Red Green Color::Blue))
+        );
+    }
+
+    #[test]
+    fn parse_expands_integer_ranges() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamEach>(quote!(each(-2..=1))),
+            Ok(quote!(-2 -1 0 1))
+        );
+        assert_eq_parsed!(
+            syn::parse2::<ParamEach>(quote!(each(0u8..3))),
+            Ok(quote!(0u8 1u8 2u8))
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_on_non_literal_ranges() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamEach>(quote!(each(0..LEN))),
+            Err(error_spanned!("each(): range bounds must be integer literals"))
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_on_unbounded_ranges() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamEach>(quote!(each(0..))),
+            Err(error_spanned!("each(): ranges must be bounded"))
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_on_oversized_ranges() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamEach>(quote!(each(0..=1024))),
+            Err(error_spanned!("each(): ranges may span at most 1024 elements"))
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_on_empty_elements() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamEach>(quote!(each(3..3))),
+            Err(error_spanned!("each(): expected at least one element"))
+        );
+    }
+
+    #[test]
+    fn parse_returns_error_on_other_expressions() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamEach>(quote!(each(values))),
+            Err(error_spanned!("each(): expected `[...]` or an integer literal range"))
+        );
+    }
+
+    #[test]
+    fn names_derive_from_elements() {
+        let each: ParamEach = syn::parse_quote!(each([Color::Red, -1, "Light Blue"]));
+
+        assert_eq!(each.names(), ["color_red", "neg_1", "light_blue"]);
+    }
+
+    #[test]
+    fn names_fall_back_to_index_on_collision() {
+        let each: ParamEach = syn::parse_quote!(each([x + 1, x * 1]));
+
+        assert_eq!(each.names(), ["0", "1"]);
+    }
+
+    #[test]
+    fn zip_parse_accepts_equal_lengths() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamZip>(quote!(zip(each([1, 2]), each(3..5)))),
+            Ok(quote!(1 2 3 4))
+        );
+    }
+
+    #[test]
+    fn zip_parse_returns_error_on_differing_lengths() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamZip>(quote!(zip(each([1, 2]), each([3])))),
+            Err(error_spanned!("zip(): each(...) lengths differ (2 vs 1)"))
+        );
+    }
+
+    #[test]
+    fn zip_parse_returns_error_on_non_each() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamZip>(quote!(zip(each([1]), [2]))),
+            Err(error_spanned!("expected identifier"))
+        );
+    }
+}
@@ -4,8 +4,9 @@ use proc_macro2::{
     TokenStream, TokenTree
 };
 use syn::{
-    Type, Pat, Attribute,
-    ItemFn, FnArg, Result,
+    Type, Pat, Attribute, Ident, Expr, Stmt,
+    ItemFn, FnArg, Generics, GenericParam,
+    WherePredicate, Result,
     parse::{
         Parse, ParseStream
     },
@@ -16,23 +17,30 @@ use crate::{
     common::{
         macros::error_spanned,
         greedy_parse_with_delim,
+        tokens_contain_ident,
         peek_next_tt
     },
     params::{
-        Mutate, macros::*
+        Mutate, macros::*,
+        case::CaseInput
     }
 };
 
 mod assignment;
 mod verbatim;
+mod each;
 
-use self::{assignment::*, verbatim::*};
+use self::{assignment::*, verbatim::*, each::*};
+pub(crate) use self::each::{parse_elements, element_names};
 
 #[derive(Clone)]
 enum ParamWithInner {
     // Mutators should be defined in the order they must apply
     Assignment(ParamAssignment),
-    Verbatim(ParamVerbatim)
+    Verbatim(ParamVerbatim),
+    // Expanded into assignments prior to mutation, see `ParamWith::expand`
+    Each(ParamEach),
+    Zip(ParamZip)
 }
 
 impl Parse for ParamWithInner {
@@ -46,6 +54,12 @@ impl Parse for ParamWithInner {
             b"verbatim" => {
                 Ok(ParamWithInner::Verbatim(input.parse::<ParamVerbatim>()?))
             },
+            b"each" => {
+                Ok(ParamWithInner::Each(input.parse::<ParamEach>()?))
+            },
+            b"zip" => {
+                Ok(ParamWithInner::Zip(input.parse::<ParamZip>()?))
+            },
             _ => {
                 // Might be Union/Struct Tuple/Enum Variant - attempt to parse
                 Ok(ParamWithInner::Assignment(input.parse::<ParamAssignment>()?))
@@ -58,7 +72,9 @@ impl ToTokens for ParamWithInner {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
             ParamWithInner::Assignment(item) => item.to_tokens(tokens),
-            ParamWithInner::Verbatim(item) => item.to_tokens(tokens)
+            ParamWithInner::Verbatim(item) => item.to_tokens(tokens),
+            ParamWithInner::Each(item) => item.to_tokens(tokens),
+            ParamWithInner::Zip(item) => item.to_tokens(tokens)
         }
     }
 }
@@ -69,7 +85,10 @@ impl Mutate for ParamWithInner {
     fn mutate(&self, target: &mut Self::Item) -> Result<()> {
         match self {
             ParamWithInner::Assignment(item) => item.mutate(target),
-            ParamWithInner::Verbatim(item) => item.mutate(target)
+            ParamWithInner::Verbatim(item) => item.mutate(target),
+            ParamWithInner::Each(_) | ParamWithInner::Zip(_) => {
+                Err(error_spanned!("each(...) must be expanded prior to binding inputs", self))
+            }
         }
     }
 }
@@ -85,6 +104,74 @@ impl Parse for ParamWith {
     }
 }
 
+impl FromIterator<Expr> for ParamWith {
+    fn from_iter<I: IntoIterator<Item = Expr>>(iter: I) -> Self {
+        Self(iter.into_iter().map(| expr | ParamAssignment::from(expr).into()).collect())
+    }
+}
+
+impl ParamWith {
+    // Each input as reported upon failure - verbatim inputs are substituted rather than
+    // bound, and destructured inputs have no single binding, so such report no value
+    pub fn inputs(&self, target: &ItemFn) -> Vec<CaseInput> {
+        self.0.iter().zip(&target.sig.inputs).map(| (inner, input) | {
+            let (name, binding) = match (inner, input) {
+                (ParamWithInner::Assignment(_), FnArg::Typed(input)) => match &*input.pat {
+                    Pat::Ident(pat) => (pat.ident.to_string(), Some(pat.ident.clone())),
+                    pat => (pat.to_token_stream().to_string(), None)
+                },
+                (_, FnArg::Typed(input)) => (input.pat.to_token_stream().to_string(), None),
+                (_, input) => (input.to_token_stream().to_string(), None)
+            };
+
+            CaseInput { name, source: inner.to_token_stream(), binding }
+        }).collect()
+    }
+
+    pub fn strategies(&self) -> Result<Vec<Expr>> {
+        self.0.iter().map(| inner | match inner {
+            ParamWithInner::Assignment(assignment) => assignment.strategy(),
+            _ => Err(error_spanned!("property(): with(...) accepts only strategy expressions", inner))
+        }).collect()
+    }
+
+    // Fans out `each(...)` & `zip(...)` into a `with(...)` per combination of their elements, each
+    // along with the name suffixes identifying it. Multiple `each(...)` combine as a cartesian product
+    pub fn expand(&self) -> Vec<(ParamWith, Vec<String>)> {
+        let mut expanded: Vec<(Vec<ParamWithInner>, Vec<String>)> = vec![(Vec::new(), Vec::new())];
+
+        for inner in &self.0 {
+            let choices: Vec<(Vec<ParamWithInner>, Option<String>)> = match inner {
+                ParamWithInner::Each(each) => each.0.iter().zip(each.names()).map(| (element, name) | {
+                    (vec![ParamAssignment::from(element.clone()).into()], Some(name))
+                }).collect(),
+                ParamWithInner::Zip(zip) => {
+                    let names: Vec<Vec<String>> = zip.0.iter().map(ParamEach::names).collect();
+                    (0..zip.len()).map(| i | (
+                        zip.0.iter().map(| each | ParamAssignment::from(each.0[i].clone()).into()).collect(),
+                        Some(names.iter().map(| names | names[i].as_str()).collect::<Vec<&str>>().join("_"))
+                    )).collect()
+                },
+                _ => vec![(vec![inner.clone()], None)]
+            };
+
+            expanded = expanded.into_iter().flat_map(| (inners, names) | {
+                choices.iter().map(move | (choice, name) | {
+                    let mut inners = inners.clone();
+                    inners.extend(choice.iter().cloned());
+
+                    let mut names = names.clone();
+                    names.extend(name.clone());
+
+                    (inners, names)
+                })
+            }).collect();
+        }
+
+        expanded.into_iter().map(| (inners, names) | (ParamWith(inners), names)).collect()
+    }
+}
+
 impl Mutate for ParamWith {
     type Item = ItemFn;
 
@@ -100,15 +187,56 @@ impl Mutate for ParamWith {
             ));
         }
 
-        let mut inputs = core::mem::take(&mut target.sig.inputs).into_iter();
+        let inferred: Vec<Ident> = inferred_generics(target)?;
 
-        for mutator in &self.0 {
-            // Use target.sig.inputs vec as an input queue/stack -
-            // with() mutators read from this and apply their mutation to such
-            target.sig.inputs.push(inputs.next().unwrap());
+        // Identifier pastes are resolved up front, whilst each verbatim input's binding is known
+        let verbatims: Vec<(Ident, &ParamVerbatim)> = self.0.iter().zip(&target.sig.inputs).filter_map(| pair | match pair {
+            (ParamWithInner::Verbatim(verbatim), FnArg::Typed(input)) => match &*input.pat {
+                Pat::Ident(pat) => Some((pat.ident.clone(), verbatim)),
+                _ => None
+            },
+            _ => None
+        }).collect();
+
+        if !verbatims.is_empty() {
+            *target = syn::parse2::<ItemFn>(paste_identifiers(target.to_token_stream(), &verbatims)?)?;
+        }
+
+        let check: Option<Stmt> = render_inputs_check(target, &inferred);
+        let body_len: usize = target.block.stmts.len();
+
+        // Use target.sig.inputs as an input stack - with() mutators pop their input from such,
+        // so are applied last to first. Each prepends to the function body, thus inputs are
+        // bound in declaration order: later inputs may reference those declared before them
+        for mutator in self.0.iter().rev() {
             mutator.mutate(target)?;
         }
 
+        if let Some(check) = check {
+            let bound: usize = target.block.stmts.len() - body_len;
+            target.block.stmts.insert(bound, check);
+        }
+
+        // Test functions can't be generic - generics inferred from inputs
+        // are declared on each input's binding instead, see `ParamAssignment`
+        let generics = &mut target.sig.generics;
+        generics.params = core::mem::take(&mut generics.params).into_iter().filter(| param | match param {
+            GenericParam::Type(param) => !inferred.contains(&param.ident),
+            GenericParam::Const(param) => !inferred.contains(&param.ident),
+            GenericParam::Lifetime(_) => true
+        }).collect();
+
+        if let Some(where_clause) = &mut generics.where_clause {
+            where_clause.predicates = core::mem::take(&mut where_clause.predicates).into_iter().filter(| predicate | {
+                !inferred.iter().any(| ident | tokens_contain_ident(predicate.to_token_stream(), ident))
+            }).collect();
+        }
+
+        if generics.params.is_empty() {
+            generics.lt_token = None;
+            generics.gt_token = None;
+        }
+
         Ok(())
     }
 }
@@ -116,6 +244,95 @@ impl Mutate for ParamWith {
 impl_unique!(ParamWith);
 impl_param!(ParamWith, iterable(0));
 
+fn inferred_generics(target: &ItemFn) -> Result<Vec<Ident>> {
+    let mut out: Vec<Ident> = Vec::new();
+
+    for param in &target.sig.generics.params {
+        let ident = match param {
+            GenericParam::Type(param) => &param.ident,
+            GenericParam::Const(param) => &param.ident,
+            GenericParam::Lifetime(_) => continue
+        };
+
+        if !tokens_contain_ident(target.sig.inputs.to_token_stream(), ident) {
+            continue;
+        }
+
+        if tokens_contain_ident(target.sig.output.to_token_stream(), ident) ||
+           tokens_contain_ident(target.block.to_token_stream(), ident) {
+            return Err(error_spanned!(
+                format!(
+                    "with(): `{}` is inferred from its inputs, so can't be referenced elsewhere. Consider `types({} = ...)`",
+                    ident, ident
+                ),
+                ident
+            ));
+        }
+
+        out.push(ident.clone());
+    }
+
+    Ok(out)
+}
+
+// Generics inferred from inputs may be bounded by one another, i.e `T: Into<U>` - such bounds
+// can't be checked whilst binding each input alone, so are checked once all inputs are bound,
+// by passing every input typed by such generics to a function declaring them all
+fn render_inputs_check(target: &ItemFn, inferred: &[Ident]) -> Option<Stmt> {
+    let generics: &Generics = &target.sig.generics;
+    // Generics not inferred remain upon the test function, so can't be referred to by a nested fn
+    let is_declarable = | tokens: TokenStream | !generics.params.iter().any(| param | match param {
+        GenericParam::Type(param) => !inferred.contains(&param.ident) && tokens_contain_ident(tokens.clone(), &param.ident),
+        GenericParam::Const(param) => !inferred.contains(&param.ident) && tokens_contain_ident(tokens.clone(), &param.ident),
+        GenericParam::Lifetime(_) => false
+    });
+    let references = | tokens: TokenStream | inferred.iter().filter(| ident | tokens_contain_ident(tokens.clone(), ident)).count();
+
+    let mut is_related: bool = false;
+    let mut params: Vec<GenericParam> = Vec::new();
+    for param in &generics.params {
+        match param {
+            GenericParam::Type(param) if inferred.contains(&param.ident) => {
+                let mut param = param.clone();
+                param.bounds = core::mem::take(&mut param.bounds).into_iter()
+                    .filter(| bound | is_declarable(bound.to_token_stream()))
+                    .collect();
+
+                is_related |= param.bounds.iter().any(| bound | references(bound.to_token_stream()) > 0);
+                params.push(GenericParam::Type(param));
+            },
+            GenericParam::Const(inner) if inferred.contains(&inner.ident) => params.push(param.clone()),
+            GenericParam::Lifetime(_) => params.push(param.clone()),
+            _ => {}
+        }
+    }
+
+    let predicates: Vec<&WherePredicate> = generics.where_clause.iter()
+        .flat_map(| where_clause | where_clause.predicates.iter())
+        .filter(| predicate | references(predicate.to_token_stream()) > 0 && is_declarable(predicate.to_token_stream()))
+        .collect();
+    is_related |= predicates.iter().any(| predicate | references(predicate.to_token_stream()) > 1);
+
+    if !is_related {
+        return None;
+    }
+
+    let (bindings, tys): (Vec<&Ident>, Vec<&Type>) = target.sig.inputs.iter().filter_map(| input | match input {
+        FnArg::Typed(input) => match &*input.pat {
+            Pat::Ident(pat) if references(input.ty.to_token_stream()) > 0 => Some((&pat.ident, &*input.ty)),
+            _ => None
+        },
+        _ => None
+    }).unzip();
+
+    Some(syn::parse_quote!{
+        {
+            fn __sith_inputs<#(#params),*>(#(_: &#tys),*) where #(#predicates,)* {}
+            __sith_inputs(#(&#bindings),*);
+        }
+    })
+}
+
 fn split_rust_fn_input(fn_param: Option<&mut Pair<FnArg, Comma>>) -> Result<(&mut [Attribute], &mut Pat, &mut Type)> {
     match fn_param {
         Some(Pair::Punctuated(param, _)) | Some(Pair::End(param)) => {
@@ -136,6 +353,142 @@ mod tests {
     use super::*;
     use crate::common::tests::macros::*;
     
+    mod param_with {
+        use super::*;
+
+        use crate::core::tests::macros::*;
+
+        use quote::quote;
+        use syn::parse_quote;
+
+        #[test]
+        fn mutate_removes_generics_inferred_from_inputs() {
+            let mut target: ItemFn = parse_quote!{
+                fn input<'a, T: Copy>(input: T) where T: Ord {}
+            };
+
+            let param: ParamWith = parse_quote!(1);
+            assert_eq_mutate!(param, &mut target, Ok(()));
+
+            assert_eq_tokens!(target.sig.generics, quote!(<'a>));
+            assert_eq_tokens!(target.sig.generics.where_clause, quote!());
+        }
+
+        #[test]
+        fn mutate_checks_bounds_relating_inferred_generics() {
+            let mut target: ItemFn = parse_quote!{
+                fn input<'a, T: Into<U> + Copy, U: Copy>(value: T, limit: &'a U) where U: PartialOrd {
+                    body();
+                }
+            };
+
+            let param: ParamWith = parse_quote!(5u8, &10u64);
+            assert_eq_mutate!(param, &mut target, Ok(()));
+
+            assert_eq_tokens!(target.block.stmts[2], quote!{
+                {
+                    fn __sith_inputs<'a, T: Into<U> + Copy, U: Copy>(_: &T, _: & &'a U) where U: PartialOrd, {}
+                    __sith_inputs(&value, &limit);
+                }
+            });
+            assert_eq_tokens!(target.block.stmts[3], quote!(body();));
+        }
+
+        #[test]
+        fn mutate_returns_error_when_inferred_generic_referenced_in_body() {
+            let mut target: ItemFn = parse_quote!{
+                fn input<T: Copy>(input: T) {
+                    let copy: T = input;
+                }
+            };
+
+            assert_eq_mutate!(
+                ParamWith(vec![parse_quote!(1)]), &mut target,
+                Err(error_spanned!(
+                    "with(): `T` is inferred from its inputs, so can't be referenced elsewhere. Consider `types(T = ...)`"
+                ))
+            );
+        }
+
+        #[test]
+        fn mutate_binds_inputs_in_declaration_order() {
+            let mut target: ItemFn = parse_quote!{
+                fn input(size: usize, doubled: usize) {
+                    body();
+                }
+            };
+
+            let param: ParamWith = parse_quote!(10, size * 2);
+            assert_eq_mutate!(param, &mut target, Ok(()));
+
+            assert_eq_tokens!(target.block, quote!({
+                let size: usize = 10;
+                let doubled: usize = size * 2;
+                body();
+            }));
+        }
+
+        #[test]
+        fn mutate_pastes_identifiers_from_many_verbatims() {
+            let mut target: ItemFn = parse_quote!{
+                fn input(r#prefix: _, r#field: _) {
+                    let value = call!([< r#prefix _ r#field >]);
+                }
+            };
+
+            let param: ParamWith = parse_quote!(verbatim(get), verbatim(len));
+            assert_eq_mutate!(param, &mut target, Ok(()));
+
+            assert_eq_tokens!(target.block, quote!({
+                let value = call!(get_len);
+            }));
+        }
+
+        #[test]
+        fn expand_is_cartesian_product_of_each() {
+            let param: ParamWith = parse_quote!(each([Red, Green]), 1, each(0..2));
+            let expanded: Vec<(ParamWith, Vec<String>)> = param.expand();
+
+            assert_eq!(expanded.len(), 4);
+            assert_eq_tokens!(expanded[1].0, quote!(Red 1 1));
+            assert_eq!(expanded[1].1, ["red", "1"]);
+            assert_eq_tokens!(expanded[2].0, quote!(Green 1 0));
+            assert_eq!(expanded[2].1, ["green", "0"]);
+        }
+
+        #[test]
+        fn expand_pairs_zipped_each() {
+            let param: ParamWith = parse_quote!(zip(each([Red, Green]), each(0..2)));
+            let expanded: Vec<(ParamWith, Vec<String>)> = param.expand();
+
+            assert_eq!(expanded.len(), 2);
+            assert_eq_tokens!(expanded[1].0, quote!(Green 1));
+            assert_eq!(expanded[1].1, ["green_1"]);
+        }
+
+        #[test]
+        fn expand_is_identity_without_each() {
+            let param: ParamWith = parse_quote!(1, verbatim(u8));
+            let expanded: Vec<(ParamWith, Vec<String>)> = param.expand();
+
+            assert_eq!(expanded.len(), 1);
+            assert_eq_tokens!(expanded[0].0, quote!(1 u8));
+            assert!(expanded[0].1.is_empty());
+        }
+
+        #[test]
+        fn mutate_returns_error_on_input_count_mismatch() {
+            let mut target: ItemFn = parse_quote!{
+                fn input(first: usize, second: usize) {}
+            };
+
+            assert_eq_mutate!(
+                ParamWith(vec![parse_quote!(1)]), &mut target,
+                Err(error_spanned!("with(): 2 fn inputs but only 1 args declared"))
+            );
+        }
+    }
+
     mod split_rust_fn_input {
         use super::*;
 
@@ -1,24 +1,25 @@
 use quote::ToTokens;
 
 use proc_macro2::{
-    TokenStream, TokenTree,
-    Group
+    TokenStream, TokenTree, Group, Delimiter
 };
 use syn::{
-    Ident, Type, Pat,
-    ItemFn, Stmt, Result,
+    Ident, Type, Pat, Lit,
+    ItemFn, Result,
     parse::{
         Parse, ParseStream
     },
-    buffer::{
-        TokenBuffer, Cursor
-    }
+    buffer::TokenBuffer
 };
 use super::{
     ParamWithInner, split_rust_fn_input
 };
 use crate::{
-    common::macros::error_spanned,
+    common::{
+        recursive_descent_replace,
+        tokens_contain_ident,
+        macros::error_spanned
+    },
     params::{
         Mutate, macros::*, parse_param_args
     }
@@ -40,58 +41,116 @@ impl Mutate for ParamVerbatim {
     fn mutate(&self, target: &mut Self::Item) -> Result<()> {
         match split_rust_fn_input(target.sig.inputs.pop().as_mut())? {
             (_, Pat::Ident(def), Type::Infer(_)) => {
-                for stmt in &mut target.block.stmts {
-                    let tokens = recursive_descent_replace(
-                        &mut TokenBuffer::new2(stmt.to_token_stream()).begin(),
-                        &def.ident,
-                        &self.0
-                    );
-                    *stmt = syn::parse2::<Stmt>(tokens)?;
-                }
+                // The consumed input has been popped, so the remainder of the function -
+                // attributes, signature, generics, where clause & body - is substituted
+                let tokens = recursive_descent_replace(
+                    &mut TokenBuffer::new2(target.to_token_stream()).begin(),
+                    &def.ident,
+                    &self.0
+                );
+                *target = syn::parse2::<ItemFn>(tokens)?;
 
                 Ok(())
             },
-            (_, _, ty) => {
+            (_, Pat::Ident(_), ty) => {
                 Err(error_spanned!("verbatim inputs must be tagged as `_`", ty))
+            },
+            (_, pat, _) => {
+                Err(error_spanned!("verbatim inputs must be bound to an identifier", pat))
             }
         }
     }
 }
 
-impl From<ParamVerbatim> for ParamWithInner {
-    fn from(value: ParamVerbatim) -> Self {
-        ParamWithInner::Verbatim(value)
+// Resolves `[< ... >]` groups into a single identifier, substituting the verbatim inputs given,
+// i.e `[< get_ r#field >]` => `get_len`. Pastes aren't valid Rust syntax, so as with the `paste`
+// crate, are only accepted by rustc within macro invocations
+pub(super) fn paste_identifiers(tokens: TokenStream, inputs: &[(Ident, &ParamVerbatim)]) -> Result<TokenStream> {
+    let mut out = TokenStream::new();
+    for tt in tokens {
+        match tt {
+            TokenTree::Group(group) if is_paste_group(&group) => {
+                let mut stream = group.stream();
+                let mut pasted: Vec<&ParamVerbatim> = Vec::new();
+                for (ident, value) in inputs {
+                    if tokens_contain_ident(stream.clone(), ident) {
+                        stream = recursive_descent_replace(&mut TokenBuffer::new2(stream).begin(), ident, &value.0);
+                        pasted.push(value);
+                    }
+                }
+
+                // Reported against the usage site, along with each case value pasted into it
+                let ident = paste_identifier(stream, &group).map_err(| mut error | {
+                    let message = error.to_string();
+                    for value in pasted {
+                        error.combine(error_spanned!(&message, &value.0));
+                    }
+
+                    error
+                })?;
+
+                ident.to_tokens(&mut out);
+            },
+            TokenTree::Group(group) => {
+                let mut resolved = Group::new(group.delimiter(), paste_identifiers(group.stream(), inputs)?);
+                resolved.set_span(group.span());
+                resolved.to_tokens(&mut out);
+            },
+            _ => {
+                tt.to_tokens(&mut out);
+            }
+        }
     }
+
+    Ok(out)
 }
 
-impl_param!(ParamVerbatim, 0);
+fn is_paste_group(group: &Group) -> bool {
+    let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
 
-fn recursive_descent_replace(input: &mut Cursor, pattern: &Ident, substitute: &TokenStream) -> TokenStream {
-    let mut out = TokenStream::new();
-    while let Some((tt, next)) = input.token_tree() {
-        match tt {
-            TokenTree::Group(item) => {
-                let (mut start, _, _) = input.group(item.delimiter()).unwrap();
+    group.delimiter() == Delimiter::Bracket && tokens.len() > 2 && matches!(
+        (tokens.first(), tokens.last()),
+        (Some(TokenTree::Punct(open)), Some(TokenTree::Punct(close))) if open.as_char() == '<' && close.as_char() == '>'
+    )
+}
 
-                Group::new(
-                    item.delimiter(),
-                    recursive_descent_replace(&mut start, pattern, substitute)
-                ).to_tokens(&mut out);
+fn paste_identifier(stream: TokenStream, group: &Group) -> Result<Ident> {
+    let tokens: Vec<TokenTree> = stream.into_iter().collect();
+
+    let mut pasted = String::new();
+    for fragment in &tokens[1..tokens.len() - 1] {
+        match fragment {
+            TokenTree::Ident(ident) => {
+                pasted += ident.to_string().trim_start_matches("r#");
             },
-            TokenTree::Ident(item) if item.eq(pattern) => {
-                substitute.to_tokens(&mut out);
+            TokenTree::Literal(literal) => match Lit::new(literal.clone()) {
+                Lit::Int(int) => pasted += int.base10_digits(),
+                Lit::Str(string) => pasted += &string.value(),
+                _ => {
+                    return Err(error_spanned!(format!("can't paste `{}` into an identifier", literal), group));
+                }
             },
             _ => {
-                tt.to_tokens(&mut out);
+                return Err(error_spanned!(format!("can't paste `{}` into an identifier", fragment), group));
             }
         }
+    }
 
-        *input = next;
+    if syn::parse_str::<Ident>(&pasted).is_err() {
+        return Err(error_spanned!(format!("pasted `{}` is not a valid identifier", pasted), group));
     }
 
-    out
+    Ok(Ident::new(&pasted, group.span()))
 }
 
+impl From<ParamVerbatim> for ParamWithInner {
+    fn from(value: ParamVerbatim) -> Self {
+        ParamWithInner::Verbatim(value)
+    }
+}
+
+impl_param!(ParamVerbatim, 0);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,6 +206,44 @@ mod tests {
         });
     }
 
+    #[test]
+    fn mutate_returns_error_when_not_bound_to_ident() {
+        let mut target: ItemFn = parse_quote!{
+            fn foo((a, b): _) {}
+        };
+
+        assert_eq_mutate!(
+            ParamVerbatim(quote!(u8)),
+            &mut target,
+            Err(error_spanned!("verbatim inputs must be bound to an identifier"))
+        );
+    }
+
+    #[test]
+    fn mutate_substitutes_throughout_function() {
+        let mut target: ItemFn = parse_quote!{
+            #[cfg_attr(r#feature, ignore)]
+            fn foo<T: From<r#replace>>(input: r#replace, r#replace: _) -> Vec<r#replace> where r#replace: Copy {
+                fn nested(value: r#replace) {}
+                vec![input as r#replace]
+            }
+        };
+
+        assert_eq_mutate!(
+            ParamVerbatim(quote!(u8)),
+            &mut target,
+            Ok(())
+        );
+
+        assert_eq_tokens!(target, quote!{
+            #[cfg_attr(r#feature, ignore)]
+            fn foo<T: From<u8> >(input: u8,) -> Vec<u8> where u8: Copy {
+                fn nested(value: u8) {}
+                vec![input as u8]
+            }
+        });
+    }
+
     #[test]
     fn mutate_returns_error_when_not_annotated_as_infer_type() {
         let mut target: ItemFn = parse_quote!{
@@ -162,100 +259,43 @@ mod tests {
         );
     }
 
-    mod recursive_descent_replace {
-        use super::*;
-
-        use syn::{
-            parse_quote,
-            buffer::TokenBuffer
-        };
+    #[test]
+    fn paste_identifiers_substitutes_inputs() {
+        let value = ParamVerbatim(quote!(len));
+        let pasted = paste_identifiers(
+            quote!(v.[< get_ r#field >]() == Self::[< MAX_ 8 >]),
+            &[(parse_quote!(r#field), &value)]
+        );
 
-        #[test]
-        fn works_within_nested_parenthesis() {
-            let target = TokenBuffer::new2(quote!{
-                let val: usize = foo(r#replace, bar(r#replace));
-            });
-
-            let new = recursive_descent_replace(
-                &mut target.begin(),
-                &parse_quote!(r#replace),
-                &quote!(123)
-            );
-
-            assert_eq_tokens!(new, quote!{
-                let val: usize = foo(123, bar(123));
-            });
-        }
+        assert_eq_parsed!(pasted, Ok(quote!(v.get_len() == Self::MAX_8)));
+    }
 
-        #[test]
-        fn works_within_nested_braces() {
-            let target = TokenBuffer::new2(quote!{
-                let val: Matrix = if true {
-                    Matrix::M2 { x: r#replace, y: 1 }
-                } else {
-                    Matrix::M2 { x: 1, y: r#replace }
-                }
-            });
-
-            let new = recursive_descent_replace(
-                &mut target.begin(),
-                &parse_quote!(r#replace),
-                &quote!(123)
-            );
-
-            assert_eq_tokens!(new, quote!{
-                let val: Matrix = if true {
-                    Matrix::M2 { x: 123, y: 1 }
-                } else {
-                    Matrix::M2 { x: 1, y: 123 }
-                }
-            });
-        }
+    #[test]
+    fn paste_identifiers_ignores_non_paste_groups() {
+        assert_eq_parsed!(
+            paste_identifiers(quote!([<u8>::MAX, 1] [<T as Trait>::X]), &[]),
+            Ok(quote!([<u8>::MAX, 1] [<T as Trait>::X]))
+        );
+    }
 
-        #[test]
-        fn works_within_nested_brackets() {
-            let target = TokenBuffer::new2(quote!{
-                let tiles: [Chunk<[Tile; r#replace]>; r#replace];
-            });
-
-            let new = recursive_descent_replace(
-                &mut target.begin(),
-                &parse_quote!(r#replace),
-                &quote!(64)
-            );
-
-            assert_eq_tokens!(new, quote!{
-                let tiles: [Chunk<[Tile; 64]>; 64];
-            });
-        }
+    #[test]
+    fn paste_identifiers_returns_error_on_unpastable_tokens() {
+        assert_eq_parsed!(
+            paste_identifiers(quote!([< get_ (field) >]), &[]),
+            Err(error_spanned!("can't paste `(field)` into an identifier"))
+        );
+    }
 
-        #[test]
-        fn works_with_empty_substitution_tokens() {
-            let target = TokenBuffer::new2(quote!());
-            let new = recursive_descent_replace(
-                &mut target.begin(),
-                &parse_quote!(r#replace),
-                &quote!(64)
-            );
+    #[test]
+    fn paste_identifiers_returns_error_against_usage_and_value() {
+        let value = ParamVerbatim(quote!(0));
 
-            assert_eq_tokens!(new, quote!());
-        }
+        let mut expected = error_spanned!("pasted `0_suffix` is not a valid identifier");
+        expected.combine(error_spanned!("pasted `0_suffix` is not a valid identifier"));
 
-        #[test]
-        fn result_is_unchanged_when_no_matches_found() {
-            let target = TokenBuffer::new2(quote!{
-                let a: usize = usize::MAX;
-            });
-
-            let new = recursive_descent_replace(
-                &mut target.begin(),
-                &parse_quote!(r#replace),
-                &quote!(64)
-            );
-
-            assert_eq_tokens!(new, quote!{
-                let a: usize = usize::MAX;
-            });
-        }
+        assert_eq_parsed!(
+            paste_identifiers(quote!([< r#field _suffix >]), &[(parse_quote!(r#field), &value)]),
+            Err(expected)
+        );
     }
-}
\ No newline at end of file
+}
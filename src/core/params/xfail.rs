@@ -0,0 +1,110 @@
+use syn::{
+    LitStr, ItemFn, ReturnType, Result,
+    ext::IdentExt,
+    parse::{
+        Parse, ParseStream
+    }
+};
+use crate::{
+    common::macros::error_spanned,
+    params::{
+        Mutate, macros::*
+    }
+};
+
+#[derive(Clone)]
+pub(crate) struct ParamXfail(pub LitStr);
+
+impl Parse for ParamXfail {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let Ok(reason) = input.parse::<LitStr>() else {
+            return Err(error_spanned!("xfail(): expected a reason, i.e. `xfail(\"issue 142\")`", &input.span()));
+        };
+
+        Ok(Self(reason))
+    }
+}
+
+impl Mutate for ParamXfail {
+    type Item = ItemFn;
+
+    // As with retries, setup through to teardown is moved into a closure - typed explicitly,
+    // as the body may well end in a panic. Must apply after retries (should each attempt be
+    // expected to fail), and before `on_failure` - which is called only upon an unexpected pass
+    fn mutate(&self, target: &mut Self::Item) -> Result<()> {
+        if let Some(asyncness) = &target.sig.asyncness {
+            return Err(error_spanned!("xfail can't be applied to async tests", asyncness));
+        }
+
+        let name: String = target.sig.ident.unraw().to_string();
+        let reason: &LitStr = &self.0;
+        let output: syn::Type = match &target.sig.output {
+            ReturnType::Default => syn::parse_quote!(()),
+            ReturnType::Type(_, output) => *output.clone()
+        };
+        let stmts = core::mem::take(&mut target.block.stmts);
+
+        *target.block = syn::parse_quote!({
+            ::sith_runtime::xfail::run(#name, #reason, || -> #output { #(#stmts)* })
+        });
+
+        Ok(())
+    }
+}
+
+impl_unique!(ParamXfail);
+impl_param!(ParamXfail, 0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::tests::macros::*,
+        core::tests::macros::*
+    };
+
+    use quote::{
+        quote, ToTokens
+    };
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_returns_error_without_reason() {
+        assert_eq_parsed!(
+            syn::parse2::<ParamXfail>(quote!()),
+            Err(error_spanned!("xfail(): expected a reason, i.e. `xfail(\"issue 142\")`"))
+        );
+        assert_eq_parsed!(
+            syn::parse2::<ParamXfail>(quote!(issue_142)),
+            Err(error_spanned!("xfail(): expected a reason, i.e. `xfail(\"issue 142\")`"))
+        );
+    }
+
+    #[test]
+    fn mutate_expects_body_to_fail() {
+        let mut target: ItemFn = parse_quote!{
+            fn known_bug() -> Result<(), String> {
+                body()
+            }
+        };
+
+        let param: ParamXfail = parse_quote!("issue 142");
+        assert_eq_mutate!(param, &mut target, Ok(()));
+        assert_eq_tokens!(target, quote!{
+            fn known_bug() -> Result<(), String> {
+                ::sith_runtime::xfail::run("known_bug", "issue 142", | | -> Result<(), String> { body() })
+            }
+        });
+    }
+
+    #[test]
+    fn mutate_returns_error_on_async_tests() {
+        let mut target: ItemFn = parse_quote!(async fn known_bug() {});
+
+        let param: ParamXfail = parse_quote!("issue 142");
+        assert_eq_mutate!(
+            param, &mut target,
+            Err(error_spanned!("xfail can't be applied to async tests"))
+        );
+    }
+}
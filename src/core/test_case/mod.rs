@@ -1,11 +1,12 @@
 use quote::ToTokens;
 
 use proc_macro2::{
-    TokenStream, TokenTree
+    Span, TokenStream, TokenTree
 };
 use syn::{
     Attribute, AttrStyle,
-    Ident, ItemFn, Token, Result,
+    Ident, ItemFn, LitInt, Meta, Token, Result,
+    ext::IdentExt,
     parse::{
         Parse, ParseStream
     },
@@ -23,22 +24,44 @@ use crate::{
     },
     core::{
         Mutate, Mutators,
-        InsertUnique,
+        InsertUnique, TestSuite,
         macros::*
     },
     params::{
         macros::impl_param,
         parse_param_args,
-        name::*, with::*
+        name::*, types::*, with::*, pairwise::*, property::*, seed::*, on_failure::*, case::*, timeout::*, retries::*, repeat::*, serial::*, skip::*, cfg::*, attrs::*, xfail::*, tags::*, sample::*, context::*,
+        setup::*, teardown::*
     }
 };
 
 #[repr(u8)]
+#[allow(clippy::enum_variant_names)]
 #[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord)]
 enum TestMutator {
     // Mutators should be defined in the order they must apply
     ParamName(ParamName),
-    ParamWith(ParamWith)
+    ParamSuffix(ParamSuffix),
+    ParamTypes(ParamTypes),
+    ParamWith(ParamWith),
+    // Expanded into `ParamWith` prior to mutation, see `TestCase::expand`
+    ParamPairwise(ParamPairwise),
+    ParamProperty(ParamProperty),
+    // Selects among the expanded cases, see `TestCase::expand`
+    ParamSample(ParamSample),
+    ParamCfg(ParamCfg),
+    // Appended after the test attribute, see `render_test_case`
+    ParamAttrs(ParamAttrs),
+    // Applied after any suite setup, see `render_test_case`
+    ParamRepeat(ParamRepeat),
+    ParamRetries(ParamRetries),
+    ParamXfail(ParamXfail),
+    ParamTimeout(ParamTimeout),
+    ParamSeed(ParamSeed),
+    ParamSerial(ParamSerial),
+    ParamRequiresEnv(ParamRequiresEnv),
+    ParamSkipIf(ParamSkipIf),
+    ParamTags(ParamTags)
 }
 
 impl Mutate for TestMutator {
@@ -47,7 +70,16 @@ impl Mutate for TestMutator {
     fn mutate(&self, target: &mut Self::Item) -> Result<()> {
         match self {
             TestMutator::ParamWith(param) => param.mutate(target),
-            TestMutator::ParamName(param) => param.mutate(&mut target.sig)
+            TestMutator::ParamPairwise(param) => param.mutate(target),
+            TestMutator::ParamProperty(param) => param.mutate(target),
+            TestMutator::ParamCfg(param) => param.mutate(target),
+            TestMutator::ParamAttrs(param) => param.mutate(target),
+            TestMutator::ParamSample(_) | TestMutator::ParamRepeat(_) | TestMutator::ParamRetries(_) | TestMutator::ParamXfail(_) |
+            TestMutator::ParamTimeout(_) | TestMutator::ParamSeed(_) | TestMutator::ParamSerial(_) |
+            TestMutator::ParamRequiresEnv(_) | TestMutator::ParamSkipIf(_) | TestMutator::ParamTags(_) => Ok(()),
+            TestMutator::ParamTypes(param) => param.mutate(target),
+            TestMutator::ParamName(param) => param.mutate(&mut target.sig),
+            TestMutator::ParamSuffix(param) => param.mutate(&mut target.sig)
         }
     }
 }
@@ -56,7 +88,23 @@ impl ToTokens for TestMutator {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
             TestMutator::ParamWith(param) => param.to_tokens(tokens),
-            TestMutator::ParamName(param) => param.to_tokens(tokens)
+            TestMutator::ParamPairwise(param) => param.to_tokens(tokens),
+            TestMutator::ParamProperty(param) => param.to_tokens(tokens),
+            TestMutator::ParamSample(param) => param.to_tokens(tokens),
+            TestMutator::ParamCfg(param) => param.to_tokens(tokens),
+            TestMutator::ParamAttrs(param) => param.to_tokens(tokens),
+            TestMutator::ParamRepeat(param) => param.to_tokens(tokens),
+            TestMutator::ParamRetries(param) => param.to_tokens(tokens),
+            TestMutator::ParamXfail(param) => param.to_tokens(tokens),
+            TestMutator::ParamTimeout(param) => param.to_tokens(tokens),
+            TestMutator::ParamSeed(param) => param.to_tokens(tokens),
+            TestMutator::ParamSerial(param) => param.to_tokens(tokens),
+            TestMutator::ParamRequiresEnv(param) => param.to_tokens(tokens),
+            TestMutator::ParamSkipIf(param) => param.to_tokens(tokens),
+            TestMutator::ParamTags(param) => param.to_tokens(tokens),
+            TestMutator::ParamTypes(param) => param.to_tokens(tokens),
+            TestMutator::ParamName(param) => param.to_tokens(tokens),
+            TestMutator::ParamSuffix(param) => param.to_tokens(tokens)
         };
     }
 }
@@ -64,13 +112,60 @@ impl ToTokens for TestMutator {
 impl Parse for TestMutator {
     fn parse(input: ParseStream) -> Result<Self> {
         let Ok(TokenTree::Ident(name)) = parse_next_tt(input) else {
-            return Err(error_spanned!("expected one of: `name`, `arg(...)`", &input.span()));
+            return Err(error_spanned!("expected one of: `name`, `with(...)`, `types(...)`, `pairwise(...)`, `property(...)`, `sample(N)`, `cfg(...)`, `attrs(...)`, `repeat(...)`, `seed`, `serial`, `skip_if(...)`, `requires_env(...)`, `tags(...)`, `timeout = \"...\"`, `retries = N`, `xfail(\"...\")`", &input.span()));
         };
 
         match name.to_string().as_bytes() {
             b"with" => {
                 Ok(TestMutator::ParamWith(parse_param_args(input)?))
             },
+            b"types" => {
+                Ok(TestMutator::ParamTypes(parse_param_args(input)?))
+            },
+            b"pairwise" => {
+                Ok(TestMutator::ParamPairwise(parse_param_args(input)?))
+            },
+            b"property" => {
+                Ok(TestMutator::ParamProperty(parse_param_args(input)?))
+            },
+            b"sample" => {
+                Ok(TestMutator::ParamSample(parse_param_args(input)?))
+            },
+            b"repeat" => {
+                Ok(TestMutator::ParamRepeat(parse_param_args(input)?))
+            },
+            b"cfg" => {
+                Ok(TestMutator::ParamCfg(parse_param_args(input)?))
+            },
+            b"attrs" => {
+                Ok(TestMutator::ParamAttrs(parse_param_args(input)?))
+            },
+            b"xfail" => {
+                Ok(TestMutator::ParamXfail(parse_param_args(input)?))
+            },
+            b"tags" => {
+                Ok(TestMutator::ParamTags(parse_param_args(input)?))
+            },
+            b"seed" if !input.peek(Paren) => {
+                Ok(TestMutator::ParamSeed(ParamSeed(name)))
+            },
+            b"serial" => {
+                Ok(TestMutator::ParamSerial(ParamSerial::parse_group(name, input)?))
+            },
+            b"skip_if" => {
+                Ok(TestMutator::ParamSkipIf(parse_param_args(input)?))
+            },
+            b"requires_env" => {
+                Ok(TestMutator::ParamRequiresEnv(parse_param_args(input)?))
+            },
+            b"timeout" if input.peek(Token![=]) => {
+                input.parse::<Token![=]>()?;
+                Ok(TestMutator::ParamTimeout(input.parse()?))
+            },
+            b"retries" if input.peek(Token![=]) => {
+                input.parse::<Token![=]>()?;
+                Ok(TestMutator::ParamRetries(input.parse()?))
+            },
             _ => {
                 if input.peek(Paren) {
                     return Err(
@@ -124,25 +219,311 @@ impl TestCase {
     pub const SITH_TEST_IDENT: &'static str = "test_case";
     pub const RUSTC_TEST_IDENT: &'static str = "test";
     pub const WASM_TEST_IDENT: &'static str = "wasm_bindgen_test";
+
+    // Fans out into a test case per element of any `each(...)` within `with(...)`,
+    // or per row of the covering generated by `pairwise(...)`, then `repeat(expand = N)`
+    // fans out each of those into N tests suffixed by their index. Should there be more
+    // than one, each is sampled by `sample(N)` - or, under the `runtime-filter` feature,
+    // `SITH_SAMPLE` - as it runs
+    pub fn expand(self, target: &ItemFn) -> Result<Vec<TestCase>> {
+        let count: Option<LitInt> = self.sample().and_then(| param | param.count.clone());
+        let cases: Vec<TestCase> = self.expand_repeats(target)?;
+        if cases.len() < 2 || (count.is_none() && !cfg!(feature = "runtime-filter")) {
+            return Ok(cases);
+        }
+
+        let (group, total): (String, usize) = (target.sig.ident.unraw().to_string(), cases.len());
+        Ok(cases.into_iter().enumerate().map(| (index, mut case) | {
+            case.0.replace(TestMutator::ParamSample(ParamSample::of_case(count.clone(), group.clone(), index, total)));
+            case
+        }).collect())
+    }
+
+    fn expand_repeats(self, target: &ItemFn) -> Result<Vec<TestCase>> {
+        let expansions: Option<u64> = self.repeat().and_then(ParamRepeat::expansions);
+        let cases: Vec<TestCase> = self.expand_inputs(target)?;
+        let Some(expansions) = expansions else {
+            return Ok(cases);
+        };
+
+        Ok(cases.into_iter().flat_map(| TestCase(mutators) | (0..expansions).map(move | index | {
+            let mut mutators: Mutators<TestMutator> = mutators.clone();
+            mutators.retain(| mutator | !matches!(mutator, TestMutator::ParamRepeat(_)));
+
+            let mut suffix: Vec<String> = match mutators.take(&TestMutator::ParamSuffix(ParamSuffix(Vec::new()))) {
+                Some(TestMutator::ParamSuffix(ParamSuffix(suffix))) => suffix,
+                _ => Vec::new()
+            };
+            suffix.push(index.to_string());
+            mutators.insert(TestMutator::ParamSuffix(ParamSuffix(suffix)));
+
+            TestCase(mutators)
+        })).collect())
+    }
+
+    fn expand_inputs(self, target: &ItemFn) -> Result<Vec<TestCase>> {
+        let with = self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamWith(param) => Some(param),
+            _ => None
+        });
+        let pairwise = self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamPairwise(param) => Some(param),
+            _ => None
+        });
+        let property = self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamProperty(param) => Some(param),
+            _ => None
+        });
+
+        // Property tests generate their inputs at runtime - `with(...)` declares strategies instead
+        if let Some(property) = property {
+            if let Some(pairwise) = pairwise {
+                return Err(error_spanned!("property() can't be combined with `pairwise(...)`", pairwise));
+            }
+
+            let mut property = property.clone();
+            property.strategies = with.map(ParamWith::strategies).transpose()?;
+
+            let mut mutators: Mutators<TestMutator> = self.0.clone();
+            mutators.retain(| mutator | !matches!(mutator, TestMutator::ParamWith(_)));
+            mutators.replace(TestMutator::ParamProperty(property));
+
+            return Ok(vec![TestCase(mutators)]);
+        }
+
+        let expanded: Vec<(ParamWith, Vec<String>)> = match (with, pairwise) {
+            (Some(_), Some(pairwise)) => {
+                return Err(error_spanned!("pairwise() can't be combined with `with(...)`", pairwise));
+            },
+            (Some(with), None) => with.expand(),
+            (None, Some(pairwise)) => pairwise.expand(target)?,
+            (None, None) => {
+                return Ok(vec![self]);
+            }
+        };
+
+        Ok(expanded.into_iter().map(| (param, suffix) | {
+            let mut mutators: Mutators<TestMutator> = self.0.clone();
+            mutators.retain(| mutator | !matches!(mutator, TestMutator::ParamPairwise(_)));
+            mutators.replace(TestMutator::ParamWith(param));
+            if !suffix.is_empty() {
+                mutators.insert(TestMutator::ParamSuffix(ParamSuffix(suffix)));
+            }
+
+            TestCase(mutators)
+        }).collect())
+    }
+
+    // Parses `test_case` or `test_case(...)`, as found within `#[cfg_attr(...)]`
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        match meta {
+            Meta::Path(_) => Ok(Self(Mutators::new())),
+            Meta::List(list) => list.parse_args_with(Self::parse),
+            Meta::NameValue(_) => Err(error_spanned!("expected `test_case(...)`", meta))
+        }
+    }
+
+    // Restricts the case to `cfg`, in addition to any predicate of its own
+    fn with_cfg(mut self, cfg: &ParamCfg) -> Self {
+        let own: Option<ParamCfg> = self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamCfg(param) => Some(param.clone()),
+            _ => None
+        });
+
+        self.0.replace(TestMutator::ParamCfg(match own {
+            Some(own) => cfg.and(&own),
+            None => cfg.clone()
+        }));
+
+        self
+    }
+
+    // Appends `attr` to those of `attrs(...)`, if any
+    fn sample(&self) -> Option<&ParamSample> {
+        self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamSample(param) => Some(param),
+            _ => None
+        })
+    }
+
+    fn seed(&self) -> Option<&ParamSeed> {
+        self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamSeed(param) => Some(param),
+            _ => None
+        })
+    }
+
+    fn repeat(&self) -> Option<&ParamRepeat> {
+        self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamRepeat(param) => Some(param),
+            _ => None
+        })
+    }
+
+    fn retries(&self) -> Option<&ParamRetries> {
+        self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamRetries(param) => Some(param),
+            _ => None
+        })
+    }
+
+    fn serial(&self) -> Option<&ParamSerial> {
+        self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamSerial(param) => Some(param),
+            _ => None
+        })
+    }
+
+    fn skip_if(&self) -> Option<&ParamSkipIf> {
+        self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamSkipIf(param) => Some(param),
+            _ => None
+        })
+    }
+
+    fn requires_env(&self) -> Option<&ParamRequiresEnv> {
+        self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamRequiresEnv(param) => Some(param),
+            _ => None
+        })
+    }
+
+    fn xfail(&self) -> Option<&ParamXfail> {
+        self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamXfail(param) => Some(param),
+            _ => None
+        })
+    }
+
+    fn tags(&self) -> Option<&ParamTags> {
+        self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamTags(param) => Some(param),
+            _ => None
+        })
+    }
+
+    fn timeout(&self) -> Option<&ParamTimeout> {
+        self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamTimeout(param) => Some(param),
+            _ => None
+        })
+    }
+
+    // Cases with inputs are reported upon failure under the `case-reports` feature, so plain tests
+    // needn't reference `sith_runtime` - as are those reported to an `on_failure` hook regardless
+    fn case(&self, span: Span, target: &ItemFn, is_reported: bool) -> Option<ParamCase> {
+        let with: Option<&ParamWith> = self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamWith(param) => Some(param),
+            _ => None
+        });
+
+        match with {
+            Some(with) if cfg!(feature = "case-reports") || is_reported => {
+                Some(ParamCase::new(span, with.inputs(target)))
+            },
+            None if is_reported => Some(ParamCase::new(span, Vec::new())),
+            _ => None
+        }
+    }
+
+    fn inputs(&self, target: &ItemFn) -> Vec<CaseInput> {
+        self.0.iter().find_map(| mutator | match mutator {
+            TestMutator::ParamWith(param) => Some(param.inputs(target)),
+            _ => None
+        }).unwrap_or_default()
+    }
+
+    // The name & suffixes appended to that of the function, i.e. `small_red`
+    fn case_name(&self) -> Option<String> {
+        let mut parts: Vec<String> = Vec::new();
+        for mutator in &self.0 {
+            match mutator {
+                TestMutator::ParamName(ParamName(name)) => parts.push(name.to_string()),
+                TestMutator::ParamSuffix(ParamSuffix(suffix)) => parts.extend(suffix.iter().cloned()),
+                _ => {}
+            }
+        }
+
+        (!parts.is_empty()).then(| | parts.join("_"))
+    }
 }
 
 impl_param!(TestCase, iterable(0));
 
 pub fn render_test_case(test_case_: TestCase, mut target: ItemFn) -> TokenStream {
     let mut out: TokenStream = TokenStream::new();
-    let mut test_cases: Vec<TestCase> = vec![test_case_];
+    // Each case is located at its attribute - the call site, being that of this invocation
+    let mut test_cases: Vec<(Span, TestCase)> = vec![(Span::call_site(), test_case_)];
+    let mut setup: Option<ParamSetup> = None;
+    let mut teardown: Option<ParamTeardown> = None;
+    let mut seed: Option<ParamSeed> = None;
+    let mut on_failure: Option<ParamOnFailure> = None;
+    let mut timeout: Option<ParamTimeout> = None;
+    let mut serial: Option<ParamSerial> = None;
+    let mut skip_if: Option<ParamSkipIf> = None;
+    let mut requires_env: Option<ParamRequiresEnv> = None;
+    let mut tags: ParamTags = ParamTags::default();
+    let mut context: ParamContext = ParamContext::default();
 
-    // Search for other test case attributes, plucking such from the fn def if present
+    // Search for other test case attributes, plucking such from the fn def if present.
+    // Setup/teardown/seed/on_failure/timeout/serial/skip conditions/tags/name forwarded from an enclosing #[test_suite] are plucked likewise
     let mut removed_elements: usize = 0;
     for i in 0..target.attrs.len() {
-        if attribute_name_to_string(&target.attrs[i - removed_elements]).as_str() != TestCase::SITH_TEST_IDENT {
-            continue;
-        }
+        let attr: &Attribute = &target.attrs[i - removed_elements];
+        match attribute_name_to_string(attr).as_str() {
+            TestCase::SITH_TEST_IDENT => {
+                test_cases.push((attr.span(), unwrap_or_err!(attr.parse_args_with(TestCase::parse))));
+            },
+            // `#[cfg_attr(pred, test_case(...))]` - such cases are only compiled under `pred`
+            ParamCfg::CFG_ATTR_IDENT => {
+                let Some((cfg, cases, rest)) = split_cfg_attr(attr, TestCase::SITH_TEST_IDENT) else {
+                    continue;
+                };
 
-        let attr = target.attrs.remove(i - removed_elements);
-        let parsed_test_case = unwrap_or_err!(attr.parse_args_with(TestCase::parse));
+                for case in &cases {
+                    test_cases.push((case.span(), unwrap_or_err!(TestCase::from_meta(case)).with_cfg(&cfg)));
+                }
 
-        test_cases.push(parsed_test_case);
+                // Any other attributes remain conditional upon `pred`
+                if !rest.is_empty() {
+                    target.attrs[i - removed_elements] = cfg.cfg_attr(&rest);
+                    continue;
+                }
+            },
+            TestSuite::SETUP_IDENT => {
+                setup = Some(unwrap_or_err!(attr.parse_args_with(ParamSetup::parse)));
+            },
+            TestSuite::TEARDOWN_IDENT => {
+                teardown = Some(unwrap_or_err!(attr.parse_args_with(ParamTeardown::parse)));
+            },
+            ParamSeed::IDENT => {
+                seed = Some(unwrap_or_err!(attr.parse_args_with(ParamSeed::parse)));
+            },
+            ParamOnFailure::IDENT => {
+                on_failure = Some(unwrap_or_err!(attr.parse_args_with(ParamOnFailure::parse)));
+            },
+            ParamTimeout::IDENT => {
+                timeout = Some(unwrap_or_err!(attr.parse_args_with(ParamTimeout::parse)));
+            },
+            ParamSerial::IDENT => {
+                serial = Some(unwrap_or_err!(attr.parse_args_with(ParamSerial::parse)));
+            },
+            ParamSkipIf::IDENT => {
+                skip_if = Some(unwrap_or_err!(attr.parse_args_with(ParamSkipIf::parse)));
+            },
+            ParamRequiresEnv::IDENT => {
+                requires_env = Some(unwrap_or_err!(attr.parse_args_with(ParamRequiresEnv::parse)));
+            },
+            ParamTags::IDENT => {
+                tags = unwrap_or_err!(attr.parse_args_with(ParamTags::parse));
+            },
+            ParamContext::IDENT => {
+                context = unwrap_or_err!(attr.parse_args_with(ParamContext::parse));
+            },
+            _ => continue
+        };
+
+        target.attrs.remove(i - removed_elements);
 
         // Upon removal, the vec shifts one to
         // the left (and thus - so does the length)
@@ -151,11 +532,76 @@ pub fn render_test_case(test_case_: TestCase, mut target: ItemFn) -> TokenStream
     }
 
     // For each test case matched, evaluate each against a fresh instance of the function
-    for test_case in test_cases {
+    let mut expanded: Vec<(Span, TestCase)> = Vec::new();
+    for (span, test_case) in test_cases {
+        match test_case.expand(&target) {
+            Ok(test_cases) => expanded.extend(test_cases.into_iter().map(| test_case | (span, test_case))),
+            Err(e) => e.to_compile_error().to_tokens(&mut out)
+        };
+    }
+
+    // Async tests are driven by the test attribute of their runtime, i.e. `#[tokio::test]`
+    let is_runtime_test: bool = target.sig.asyncness.is_some() && target.attrs.iter().any(| attr | {
+        attribute_name_to_string(attr).as_str() == TestCase::RUSTC_TEST_IDENT
+    });
+
+    for (index, (span, test_case)) in expanded.into_iter().enumerate() {
         let mut target_fn: ItemFn = target.clone();
-        target_fn.attrs.push(rustc_test_attribute!(target.span()));
+        if !is_runtime_test {
+            target_fn.attrs.push(rustc_test_attribute!(target.span()));
+        }
+
+        let case: Option<ParamCase> = test_case.case(span, &target_fn, on_failure.is_some());
+        if let Some(case) = &case {
+            case.record_inputs(&mut target_fn.block);
+        }
+
+        // Setup is applied last, so it's prepended before any test case inputs - bar the seed,
+        // i.e: seed -> setup -> inputs (in declaration order) -> body -> teardown. Should such
+        // panic (on every attempt, if retried) - or unexpectedly pass, if an xfail - `on_failure`
+        // is called & the case reported before the panic propagates. A test's own timeout &
+        // serial group override those of its suite, with the serial lock taken before all else -
+        // bar skip conditions, those of the test checked after those of its suite. Under the
+        // `runtime-filter` feature, every test is filtered by its tags, & those of its suite,
+        // before anything else. The context is bound ahead of setup, should the test refer to such
+        let seed: Option<&ParamSeed> = seed.as_ref().or(test_case.seed());
+        let timeout: Option<&ParamTimeout> = test_case.timeout().or(timeout.as_ref());
+        let serial: Option<&ParamSerial> = test_case.serial().or(serial.as_ref());
+        let tags: ParamTags = test_case.tags().map_or_else(| | tags.clone(), | own | tags.merge(own));
+        let context: ParamContext = ParamContext {
+            case: test_case.case_name(),
+            index,
+            inputs: test_case.inputs(&target_fn),
+            tags: tags.clone(),
+            ..context.clone()
+        };
+        // Repeats run setup & teardown per iteration, unless shared between all iterations
+        let (shared_repeat, repeat) = match test_case.repeat() {
+            Some(param) if param.is_shared_setup() => (Some(param), None),
+            param => (None, param)
+        };
+        let result = test_case.mutate(&mut target_fn)
+            .map(| _ | if cfg!(feature = "tag-names") { tags.prefix(&mut target_fn) })
+            .and_then(| _ | shared_repeat.map_or(Ok(()), | param | param.mutate(&mut target_fn)))
+            .and_then(| _ | setup.as_ref().map_or(Ok(()), | param | param.mutate(&mut target_fn.block)))
+            .and_then(| _ | teardown.as_ref().map_or(Ok(()), | param | param.mutate(&mut target_fn.block)))
+            .and_then(| _ | context.mutate(&mut target_fn))
+            .and_then(| _ | repeat.map_or(Ok(()), | param | param.mutate(&mut target_fn)))
+            .and_then(| _ | test_case.retries().map_or(Ok(()), | param | param.mutate(&mut target_fn)))
+            .and_then(| _ | test_case.xfail().map_or(Ok(()), | param | param.mutate(&mut target_fn)))
+            .and_then(| _ | on_failure.as_ref().map_or(Ok(()), | param | param.mutate(&mut target_fn)))
+            .and_then(| _ | case.as_ref().map_or(Ok(()), | param | param.mutate(&mut target_fn)))
+            .and_then(| _ | timeout.map_or(Ok(()), | param | param.mutate(&mut target_fn)))
+            .and_then(| _ | seed.map_or(Ok(()), | param | param.mutate(&mut target_fn)))
+            .and_then(| _ | serial.map_or(Ok(()), | param | param.mutate(&mut target_fn)))
+            .and_then(| _ | test_case.skip_if().map_or(Ok(()), | param | param.mutate(&mut target_fn)))
+            .and_then(| _ | test_case.requires_env().map_or(Ok(()), | param | param.mutate(&mut target_fn)))
+            .and_then(| _ | skip_if.as_ref().map_or(Ok(()), | param | param.mutate(&mut target_fn)))
+            .and_then(| _ | requires_env.as_ref().map_or(Ok(()), | param | param.mutate(&mut target_fn)))
+            .and_then(| _ | test_case.sample().map_or(Ok(()), | param | param.mutate(&mut target_fn)))
+            .and_then(| _ | if cfg!(feature = "runtime-filter") { tags.mutate(&mut target_fn) } else { Ok(()) });
 
-        match test_case.mutate(&mut target_fn) {
+        match result {
             Ok(()) => target_fn.to_tokens(&mut out),
             Err(e) => e.to_compile_error().to_tokens(&mut out)
         };
@@ -181,7 +627,59 @@ mod tests {
     fn subparams() -> TokenStream {
         quote!{
             test, // Name: v0.1.0
-            with() // With: v0.1.0
+            with(), // With: v0.1.0
+            types() // Types
+        }
+    }
+
+    // Filters a test by its tags under `runtime-filter`
+    fn filter(name: &str) -> TokenStream {
+        match cfg!(feature = "runtime-filter") {
+            true => quote!{
+                if ::sith_runtime::filter::is_filtered(::core::module_path!(), #name, &[]) {
+                    return ::sith_runtime::skip::Skipped::skipped();
+                }
+            },
+            false => quote!()
+        }
+    }
+
+    // Samples case `index` of the `cases` expanded from `group` by `SITH_SAMPLE` under `runtime-filter`
+    fn sample(name: &str, group: &str, index: usize, cases: usize) -> TokenStream {
+        match cfg!(feature = "runtime-filter") {
+            true => quote!{
+                if ::sith_runtime::sample::is_sampled_out(
+                    ::core::module_path!(), #name, #group, #index, #cases, ::core::option::Option::None
+                ) {
+                    return ::sith_runtime::skip::Skipped::skipped();
+                }
+            },
+            false => quote!()
+        }
+    }
+
+    // Declares the case of a test with `inputs`, reported upon failure under `case-reports`
+    fn case(name: &str, inputs: &[&str]) -> TokenStream {
+        match cfg!(feature = "case-reports") {
+            true => quote!{
+                let __sith_case = ::sith_runtime::Case::new(
+                    #name, ::core::file!(), ::core::line!(), &[#((#inputs, ::core::option::Option::None)),*]
+                );
+            },
+            false => quote!()
+        }
+    }
+
+    fn bind(input: &str) -> TokenStream {
+        let binding: Ident = quote::format_ident!("{}", input);
+        match cfg!(feature = "case-reports") {
+            true => quote!{
+                __sith_case.bind(#input, {
+                    use ::sith_runtime::failure::{DescribeDebug as _, DescribeOpaque as _};
+                    (&::sith_runtime::failure::Described(&#binding)).describe()
+                });
+            },
+            false => quote!()
         }
     }
 
@@ -189,13 +687,47 @@ mod tests {
     fn test_mutators_are_ordered_correctly() {
         let mutators: Mutators<TestMutator> = Mutators::from(
             [
+                TestMutator::ParamTags(parse_quote!(slow)),
+                TestMutator::ParamSkipIf(parse_quote!(true)),
+                TestMutator::ParamRequiresEnv(parse_quote!("VAR")),
+                TestMutator::ParamSerial(parse_quote!(serial)),
+                TestMutator::ParamSeed(ParamSeed(parse_quote!(seed))),
+                TestMutator::ParamTimeout(parse_quote!("1s")),
+                TestMutator::ParamXfail(parse_quote!("bug")),
+                TestMutator::ParamRetries(parse_quote!(1)),
+                TestMutator::ParamRepeat(parse_quote!(1)),
+                TestMutator::ParamAttrs(parse_quote!(#[track_caller])),
+                TestMutator::ParamCfg(parse_quote!(unix)),
+                TestMutator::ParamSample(parse_quote!(1)),
+                TestMutator::ParamProperty(parse_quote!()),
+                TestMutator::ParamPairwise(parse_quote!(a = [1])),
                 TestMutator::ParamWith(parse_quote!(with())),
+                TestMutator::ParamTypes(parse_quote!()),
+                TestMutator::ParamSuffix(ParamSuffix(Vec::new())),
                 TestMutator::ParamName(ParamName(parse_quote!(test)))
             ]
         );
 
         assert_mutator_order!(
-            TestMutator(mutators), TestMutator::ParamName(_), TestMutator::ParamWith(_)
+            TestMutator(mutators),
+            TestMutator::ParamName(_),
+            TestMutator::ParamSuffix(_),
+            TestMutator::ParamTypes(_),
+            TestMutator::ParamWith(_),
+            TestMutator::ParamPairwise(_),
+            TestMutator::ParamProperty(_),
+            TestMutator::ParamSample(_),
+            TestMutator::ParamCfg(_),
+            TestMutator::ParamAttrs(_),
+            TestMutator::ParamRepeat(_),
+            TestMutator::ParamRetries(_),
+            TestMutator::ParamXfail(_),
+            TestMutator::ParamTimeout(_),
+            TestMutator::ParamSeed(_),
+            TestMutator::ParamSerial(_),
+            TestMutator::ParamRequiresEnv(_),
+            TestMutator::ParamSkipIf(_),
+            TestMutator::ParamTags(_)
         );
     }
 
@@ -208,6 +740,7 @@ mod tests {
         assert_mutator_order!(
             TestMutator(test_case.0),
             TestMutator::ParamName(_),
+            TestMutator::ParamTypes(_),
             TestMutator::ParamWith(_)
         );
     }
@@ -246,6 +779,663 @@ mod tests {
         // It would be pretty apparenty if it weren't, however (stuff would break in integration tests)
     }
 
+    #[test]
+    fn render_applies_forwarded_setup_before_inputs() {
+        let test_case: TestCase = parse_quote!(with(setup_value + 1));
+        let target: ItemFn = parse_quote!{
+            #[setup { let setup_value = 1; }]
+            #[teardown { drop(input); }]
+            fn test(input: usize) {
+                body();
+            }
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let (case, bind) = (case("test", &["input"]), bind("input"));
+        let filter = filter("test");
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test() {
+                    #filter
+                    #case
+                    let setup_value = 1;
+                    let input: usize = setup_value + 1;
+                    #bind
+                    body();
+                    drop(input);
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_binds_seed_before_setup() {
+        let test_case: TestCase = parse_quote!(one, seed, with(seed + 1));
+        let target: ItemFn = parse_quote!{
+            #[setup { let setup_value = seed; }]
+            fn test(input: u64) {
+                body();
+            }
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let (case, bind) = (case("test_one", &["input"]), bind("input"));
+        let filter = filter("test_one");
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test_one() {
+                    #filter
+                    let __sith_seed = ::sith_runtime::seed::Seed::new("test_one");
+                    let seed: u64 = __sith_seed.value();
+                    __sith_seed.check((| | {
+                        #case
+                        let setup_value = seed;
+                        let input: u64 = seed + 1;
+                        #bind
+                        body();
+                    })())
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_binds_forwarded_seed_once() {
+        let test_case: TestCase = parse_quote!(seed);
+        let target: ItemFn = parse_quote!{
+            #[seed { seed }]
+            fn test() {}
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let filter = filter("test");
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test() {
+                    #filter
+                    let __sith_seed = ::sith_runtime::seed::Seed::new("test");
+                    let seed: u64 = __sith_seed.value();
+                    __sith_seed.check((| | {})())
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_wraps_forwarded_on_failure_around_test() {
+        let test_case: TestCase = parse_quote!(with(1, verbatim(u8)));
+        let target: ItemFn = parse_quote!{
+            #[setup { let setup_value = 1; }]
+            #[on_failure { dump }]
+            fn test(input: usize, r#type: _) {
+                body();
+            }
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let filter = filter("test");
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test() {
+                    #filter
+                    let __sith_case = ::sith_runtime::Case::new(
+                        "test", ::core::file!(), ::core::line!(),
+                        &[("input", ::core::option::Option::None), ("r#type", ::core::option::Option::None)]
+                    );
+                    match ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(| | {
+                        let setup_value = 1;
+                        let input: usize = 1;
+                        __sith_case.bind("input", {
+                            use ::sith_runtime::failure::{DescribeDebug as _, DescribeOpaque as _};
+                            (&::sith_runtime::failure::Described(&input)).describe()
+                        });
+                        body();
+                    })) {
+                        Ok(output) => output,
+                        Err(payload) => {
+                            dump(&::sith_runtime::Failure::new("test", payload.as_ref(), __sith_case.values()));
+                            ::std::panic::resume_unwind(payload)
+                        }
+                    }
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_retries_setup_through_to_teardown() {
+        let test_case: TestCase = parse_quote!(retries = 2);
+        let target: ItemFn = parse_quote!{
+            #[setup { let setup_value = 1; }]
+            #[teardown { drop(setup_value); }]
+            fn test() {
+                body();
+            }
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let filter = filter("test");
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test() {
+                    #filter
+                    ::sith_runtime::retry::run("test", 2u32, | | {
+                        let setup_value = 1;
+                        body();
+                        drop(setup_value);
+                    })
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_repeats_setup_through_to_teardown() {
+        let test_case: TestCase = parse_quote!(repeat(10));
+        let target: ItemFn = parse_quote!{
+            #[setup { let setup_value = 1; }]
+            #[teardown { drop(setup_value); }]
+            fn test() {
+                body();
+            }
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let filter = filter("test");
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test() {
+                    #filter
+                    ::sith_runtime::repeat::run("test", 10u64, | | {
+                        let setup_value = 1;
+                        body();
+                        drop(setup_value);
+                    })
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_repeats_within_shared_setup() {
+        let test_case: TestCase = parse_quote!(repeat(10, shared_setup));
+        let target: ItemFn = parse_quote!{
+            #[setup { let setup_value = 1; }]
+            #[teardown { drop(setup_value); }]
+            fn test() {
+                body();
+            }
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let filter = filter("test");
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test() {
+                    #filter
+                    let setup_value = 1;
+                    let __sith_output = ::sith_runtime::repeat::run("test", 10u64, | | { body(); });
+                    drop(setup_value);
+                    __sith_output
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_expands_repeat_into_indexed_cases() {
+        let test_case: TestCase = parse_quote!(repeat(expand = 2), with(each([1, 2])));
+        let target: ItemFn = parse_quote!{
+            fn test(a: u8) {}
+        };
+
+        let names: Vec<String> = test_case.expand(&target)
+            .expect("repeat(expand = ...) should expand")
+            .into_iter()
+            .map(| test_case | {
+                let mut target: ItemFn = target.clone();
+                assert!(test_case.mutate(&mut target).is_ok());
+                target.sig.ident.to_string()
+            })
+            .collect();
+
+        assert_eq!(names, ["test_1_0", "test_1_1", "test_2_0", "test_2_1"]);
+    }
+
+    #[test]
+    fn render_prefers_own_serial_group_over_forwarded() {
+        let test_case: TestCase = parse_quote!(serial(cwd), seed);
+        let target: ItemFn = parse_quote!{
+            #[serial { serial }]
+            #[setup { let setup_value = 1; }]
+            fn test() {
+                body();
+            }
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let filter = filter("test");
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test() {
+                    #filter
+                    let __sith_serial = ::sith_runtime::serial::lock("cwd");
+                    let __sith_seed = ::sith_runtime::seed::Seed::new("test");
+                    let seed: u64 = __sith_seed.value();
+                    __sith_seed.check((| | {
+                        let setup_value = 1;
+                        body();
+                    })())
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_checks_skip_conditions_of_suite_then_test() {
+        let test_case: TestCase = parse_quote!(skip_if(is_ci), serial);
+        let target: ItemFn = parse_quote!{
+            #[requires_env { "FIXTURES_DIR" }]
+            #[setup { let setup_value = 1; }]
+            fn test() {
+                body();
+            }
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let filter = filter("test");
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test() {
+                    #filter
+                    if ::sith_runtime::skip::requires_env("test", &["FIXTURES_DIR"]) {
+                        return ::sith_runtime::skip::Skipped::skipped();
+                    }
+                    if ::sith_runtime::skip::skip_if("test", is_ci, "`is_ci`") {
+                        return ::sith_runtime::skip::Skipped::skipped();
+                    }
+                    let __sith_serial = ::sith_runtime::serial::lock("");
+                    let setup_value = 1;
+                    body();
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_attaches_cfg_to_case() {
+        let test_case: TestCase = parse_quote!(simd, cfg(feature = "simd"));
+        let target: ItemFn = parse_quote!{
+            #[test_case(scalar)]
+            fn test() {}
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let (simd, scalar) = (filter("test_simd"), filter("test_scalar"));
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                #[cfg(feature = "simd")]
+                fn test_simd() {
+                    #simd
+                }
+                #[r#test]
+                fn test_scalar() {
+                    #scalar
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_appends_attrs_after_test_attribute() {
+        let test_case: TestCase = parse_quote!(traced, attrs(#[track_caller], #[serial_test::serial]));
+        let target: ItemFn = parse_quote!{
+            #[test_case(untraced)]
+            #[should_panic]
+            fn test() {}
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let [traced, untraced] = ["test_traced", "test_untraced"].map(| name | match cfg!(feature = "runtime-filter") {
+            true => quote!{
+                if ::sith_runtime::filter::is_filtered(::core::module_path!(), #name, &[]) {
+                    ::core::panic!("skipped, as expected to panic");
+                }
+            },
+            false => quote!()
+        });
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[should_panic]
+                #[r#test]
+                #[track_caller]
+                #[serial_test::serial]
+                fn test_traced() {
+                    #traced
+                }
+                #[should_panic]
+                #[r#test]
+                fn test_untraced() {
+                    #untraced
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_finds_cases_within_cfg_attr() {
+        let test_case: TestCase = parse_quote!(first);
+        let target: ItemFn = parse_quote!{
+            #[cfg_attr(unix, test_case(second, cfg(test)), allow(dead_code))]
+            #[cfg_attr(windows, allow(unused))]
+            fn test() {}
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let (first, second) = (filter("test_first"), filter("test_second"));
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[cfg_attr(unix, allow(dead_code))]
+                #[cfg_attr(windows, allow(unused))]
+                #[r#test]
+                fn test_first() {
+                    #first
+                }
+                #[cfg_attr(unix, allow(dead_code))]
+                #[cfg_attr(windows, allow(unused))]
+                #[r#test]
+                #[cfg(all(unix, test))]
+                fn test_second() {
+                    #second
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_expects_failure_within_on_failure() {
+        let test_case: TestCase = parse_quote!(xfail("issue 142"));
+        let target: ItemFn = parse_quote!{
+            #[on_failure { hook }]
+            #[teardown { drop(setup_value); }]
+            fn test() {
+                body();
+            }
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let filter = filter("test");
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test() {
+                    #filter
+                    let __sith_case = ::sith_runtime::Case::new("test", ::core::file!(), ::core::line!(), &[]);
+                    match ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(| | {
+                        ::sith_runtime::xfail::run("test", "issue 142", | | -> () {
+                            body();
+                            drop(setup_value);
+                        })
+                    })) {
+                        Ok(output) => output,
+                        Err(payload) => {
+                            hook(&::sith_runtime::Failure::new("test", payload.as_ref(), __sith_case.values()));
+                            ::std::panic::resume_unwind(payload)
+                        }
+                    }
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_prefers_own_timeout_over_forwarded() {
+        let test_case: TestCase = parse_quote!(timeout = "1ms");
+        let target: ItemFn = parse_quote!{
+            #[timeout { "1s" }]
+            fn test() {
+                body();
+            }
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let filter = filter("test");
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test() {
+                    #filter
+                    ::sith_runtime::timeout::run(
+                        "test", ::core::time::Duration::from_nanos(1000000u64),
+                        move | | { body(); }
+                    )
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_defers_to_runtime_test_attribute_of_async_tests() {
+        let test_case: TestCase = parse_quote!(timeout = "1s");
+        let target: ItemFn = parse_quote!{
+            #[tokio::test]
+            async fn test() {
+                body().await;
+            }
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let filter = filter("test");
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[tokio::test]
+                async fn test() {
+                    #filter
+                    ::sith_runtime::timeout::Timeout::new(
+                        "test", ::core::time::Duration::from_nanos(1000000000u64),
+                        async move { body().await; }
+                    ).await
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_expands_each_into_named_cases() {
+        let test_case: TestCase = parse_quote!(small, with(each([Red, Blue])));
+        let target: ItemFn = parse_quote!{
+            fn paint(color: Color) {}
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let (case_red, case_blue, bind) = (case("paint_small_red", &["color"]), case("paint_small_blue", &["color"]), bind("color"));
+        let (filter_red, filter_blue) = (filter("paint_small_red"), filter("paint_small_blue"));
+        let (sample_red, sample_blue) = (sample("paint_small_red", "paint", 0, 2), sample("paint_small_blue", "paint", 1, 2));
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn paint_small_red() {
+                    #filter_red
+                    #sample_red
+                    #case_red
+                    let color: Color = Red;
+                    #bind
+                }
+                #[r#test]
+                fn paint_small_blue() {
+                    #filter_blue
+                    #sample_blue
+                    #case_blue
+                    let color: Color = Blue;
+                    #bind
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn expand_returns_error_when_pairwise_combined_with_with() {
+        let test_case: TestCase = parse_quote!(with(1), pairwise(a = [1]));
+        let target: ItemFn = parse_quote!(fn test(a: u8) {});
+
+        let expanded: Result<TokenStream> = test_case.expand(&target).map(| _ | quote!());
+        assert_eq_parsed!(
+            expanded,
+            Err(error_spanned!("pairwise() can't be combined with `with(...)`"))
+        );
+    }
+
+    #[test]
+    fn render_declares_property_strategies_via_with() {
+        let test_case: TestCase = parse_quote!(property(cases = 8), with(0..10u8));
+        let target: ItemFn = parse_quote!{
+            fn test(a: u8) {}
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let filter = filter("test");
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test() {
+                    #filter
+                    ::sith_runtime::property::check("test", "(a)", 8, (0..10u8,), | (a,): (u8,) | {});
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_binds_context_ahead_of_setup() {
+        let test_case: TestCase = parse_quote!(small, with(1));
+        let target: ItemFn = parse_quote!{
+            #[setup { let dir = current_test!().name(); }]
+            #[suite { paints }]
+            fn test(size: u8) {}
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let (case, bind) = (case("test_small", &["size"]), bind("size"));
+        let filter = filter("test_small");
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test_small() {
+                    #filter
+                    #case
+                    let __sith_context = ::sith_runtime::TestContext::new(
+                        ::core::module_path!(), "test_small", ::core::option::Option::Some("paints"),
+                        ::core::option::Option::Some("small"), 0usize, &[("size", "1")], &[]
+                    );
+                    let dir = current_test!(__sith_context).name();
+                    let size: u8 = 1;
+                    #bind
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_samples_expanded_cases_at_runtime() {
+        let test_case: TestCase = parse_quote!(with(each(0..2)), sample(1));
+        let target: ItemFn = parse_quote!(fn test(a: u8) {});
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let (filter_one, filter_two) = (filter("test_0"), filter("test_1"));
+        let (case_one, case_two, bind) = (case("test_0", &["a"]), case("test_1", &["a"]), bind("a"));
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test_0() {
+                    #filter_one
+                    if ::sith_runtime::sample::is_sampled_out(
+                        ::core::module_path!(), "test_0", "test", 0usize, 2usize, ::core::option::Option::Some(1)
+                    ) {
+                        return ::sith_runtime::skip::Skipped::skipped();
+                    }
+                    #case_one
+                    let a: u8 = 0;
+                    #bind
+                }
+                #[r#test]
+                fn test_1() {
+                    #filter_two
+                    if ::sith_runtime::sample::is_sampled_out(
+                        ::core::module_path!(), "test_1", "test", 1usize, 2usize, ::core::option::Option::Some(1)
+                    ) {
+                        return ::sith_runtime::skip::Skipped::skipped();
+                    }
+                    #case_two
+                    let a: u8 = 1;
+                    #bind
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn render_expands_pairwise_into_named_cases() {
+        let test_case: TestCase = parse_quote!(pairwise(a = [1, 2]));
+        let target: ItemFn = parse_quote!{
+            fn test(a: u8) {}
+        };
+
+        let rendered: TokenStream = render_test_case(test_case, target);
+        let (case_one, case_two, bind) = (case("test_1", &["a"]), case("test_2", &["a"]), bind("a"));
+        let (filter_one, filter_two) = (filter("test_1"), filter("test_2"));
+        let (sample_one, sample_two) = (sample("test_1", "test", 0, 2), sample("test_2", "test", 1, 2));
+        assert_eq_tokens!(
+            rendered,
+            quote!{
+                #[r#test]
+                fn test_1() {
+                    #filter_one
+                    #sample_one
+                    #case_one
+                    let a: u8 = 1;
+                    #bind
+                }
+                #[r#test]
+                fn test_2() {
+                    #filter_two
+                    #sample_two
+                    #case_two
+                    let a: u8 = 2;
+                    #bind
+                }
+            }
+        );
+    }
+
     #[test]
     fn mutate_is_ok_with_no_mutators() {
         let test_case: TestCase = parse_quote!();
@@ -1,17 +1,18 @@
-use proc_macro2::TokenStream;
-
+use proc_macro2::{
+    Span, TokenStream, TokenTree
+};
 use quote::{
     ToTokens, TokenStreamExt
 };
 use syn::{
     Attribute,
-    Result, Ident,
+    Result, Ident, Token,
     ItemMod, Item, ItemFn,
     parse::{
         Parse, ParseStream
     }, 
     token::{
-        Mod, Brace
+        Mod, Brace, Paren
     }
 };
 use core::{
@@ -26,20 +27,195 @@ use crate::{
         InsertUnique, TestCase
     },
     params::{
-        setup::*, teardown::*
+        macros::impl_param,
+        parse_param_args,
+        setup::*, teardown::*,
+        for_types::*, seed::*, on_failure::*, case::*, timeout::*, serial::*, skip::*, cfg::*, tags::*, context::*
     },
     common::{
         attribute_name_to_string,
-        macros::error_spanned
+        parse_next_tt,
+        macros::{
+            unwrap_or_err,
+            error_spanned
+        }
     }
 };
 
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord)]
+enum SuiteParam {
+    // Params should be defined in the order they must apply
+    ForTypes(ParamForTypes),
+    Seed(ParamSeed),
+    Timeout(ParamTimeout),
+    Serial(ParamSerial),
+    SkipIf(ParamSkipIf),
+    RequiresEnv(ParamRequiresEnv),
+    Tags(ParamTags)
+}
+
+impl ToTokens for SuiteParam {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            SuiteParam::ForTypes(param) => param.to_tokens(tokens),
+            SuiteParam::Seed(param) => param.to_tokens(tokens),
+            SuiteParam::Timeout(param) => param.to_tokens(tokens),
+            SuiteParam::Serial(param) => param.to_tokens(tokens),
+            SuiteParam::SkipIf(param) => param.to_tokens(tokens),
+            SuiteParam::RequiresEnv(param) => param.to_tokens(tokens),
+            SuiteParam::Tags(param) => param.to_tokens(tokens)
+        };
+    }
+}
+
+impl Parse for SuiteParam {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let Ok(TokenTree::Ident(name)) = parse_next_tt(input) else {
+            return Err(error_spanned!("expected one of: `for_types(...)`, `seed`, `serial`, `skip_if(...)`, `requires_env(...)`, `tags(...)`, `timeout = \"...\"`", &input.span()));
+        };
+
+        match name.to_string().as_bytes() {
+            b"for_types" => {
+                Ok(SuiteParam::ForTypes(parse_param_args(input)?))
+            },
+            b"seed" if !input.peek(Paren) => {
+                Ok(SuiteParam::Seed(ParamSeed(name)))
+            },
+            b"timeout" if input.peek(Token![=]) => {
+                input.parse::<Token![=]>()?;
+                Ok(SuiteParam::Timeout(input.parse()?))
+            },
+            b"serial" => {
+                Ok(SuiteParam::Serial(ParamSerial::parse_group(name, input)?))
+            },
+            b"skip_if" => {
+                Ok(SuiteParam::SkipIf(parse_param_args(input)?))
+            },
+            b"requires_env" => {
+                Ok(SuiteParam::RequiresEnv(parse_param_args(input)?))
+            },
+            b"tags" => {
+                Ok(SuiteParam::Tags(parse_param_args(input)?))
+            },
+            _ => Err(error_spanned!("unrecognized arg", &name))
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct TestSuiteParams(Mutators<SuiteParam>);
+
+impl Parse for TestSuiteParams {
+    // As with TestCase, only the inner tokens of
+    // #[test_suite(...)] are passed through to here
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut params: Mutators<SuiteParam> = Mutators::new();
+
+        while !input.is_empty() {
+            params.insert_unique(input.parse::<SuiteParam>()?)?;
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(Self(params))
+    }
+}
+
+impl TestSuiteParams {
+    fn instantiate(&self, target: ItemMod) -> Result<Vec<ItemMod>> {
+        let mut instances: Vec<ItemMod> = vec![target];
+
+        for param in &self.0 {
+            match param {
+                SuiteParam::ForTypes(for_types) => {
+                    let mut out: Vec<ItemMod> = Vec::new();
+                    for instance in &instances {
+                        for type_instance in for_types.instances()? {
+                            let mut item: ItemMod = instance.clone();
+                            type_instance.mutate(&mut item)?;
+                            out.push(item);
+                        }
+                    }
+
+                    instances = out;
+                },
+                // Applied to each test within the suite, see `render_test_suite`
+                SuiteParam::Seed(_) | SuiteParam::Timeout(_) | SuiteParam::Serial(_) |
+                SuiteParam::SkipIf(_) | SuiteParam::RequiresEnv(_) | SuiteParam::Tags(_) => {}
+            }
+        }
+
+        Ok(instances)
+    }
+
+    fn seed(&self) -> Option<&ParamSeed> {
+        self.0.iter().find_map(| param | match param {
+            SuiteParam::Seed(param) => Some(param),
+            _ => None
+        })
+    }
+
+    fn timeout(&self) -> Option<&ParamTimeout> {
+        self.0.iter().find_map(| param | match param {
+            SuiteParam::Timeout(param) => Some(param),
+            _ => None
+        })
+    }
+
+    fn serial(&self) -> Option<&ParamSerial> {
+        self.0.iter().find_map(| param | match param {
+            SuiteParam::Serial(param) => Some(param),
+            _ => None
+        })
+    }
+
+    fn skip_if(&self) -> Option<&ParamSkipIf> {
+        self.0.iter().find_map(| param | match param {
+            SuiteParam::SkipIf(param) => Some(param),
+            _ => None
+        })
+    }
+
+    fn requires_env(&self) -> Option<&ParamRequiresEnv> {
+        self.0.iter().find_map(| param | match param {
+            SuiteParam::RequiresEnv(param) => Some(param),
+            _ => None
+        })
+    }
+
+    fn tags(&self) -> Option<&ParamTags> {
+        self.0.iter().find_map(| param | match param {
+            SuiteParam::Tags(param) => Some(param),
+            _ => None
+        })
+    }
+}
+
+impl_param!(TestSuiteParams, iterable(0));
+
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord)]
 enum SuiteMutator {
     // Mutators should be defined in the order they must apply
     Setup(ParamSetup),
-    Teardown(ParamTeardown)
+    Teardown(ParamTeardown),
+    // Bound ahead of setup, so setup may refer to `current_test!()`
+    Context(ParamContext),
+    // Wraps setup through to teardown
+    OnFailure(ParamOnFailure),
+    Timeout(ParamTimeout),
+    // Prepended after setup, so setup may derive from the seed
+    Seed(ParamSeed),
+    // Prepended last, so the lock is held throughout
+    Serial(ParamSerial),
+    // Checked before the lock is taken
+    SkipIf(ParamSkipIf),
+    RequiresEnv(ParamRequiresEnv),
+    // Every test is filtered by its tags (if any) before all else
+    Tags(ParamTags)
 }
 
 impl Mutate for SuiteMutator {
@@ -48,7 +224,27 @@ impl Mutate for SuiteMutator {
     fn mutate(&self, target: &mut Self::Item) -> Result<()> {
         match self {
             SuiteMutator::Setup(param) => param.mutate(&mut target.block),
-            SuiteMutator::Teardown(param) => param.mutate(&mut target.block)
+            SuiteMutator::Teardown(param) => param.mutate(&mut target.block),
+            SuiteMutator::Context(param) => param.mutate(target),
+            SuiteMutator::OnFailure(param) => {
+                let case = ParamCase::new(target.sig.ident.span(), Vec::new());
+                param.mutate(target).and_then(| _ | case.mutate(target))
+            },
+            SuiteMutator::Timeout(param) => param.mutate(target),
+            SuiteMutator::Seed(param) => param.mutate(target),
+            SuiteMutator::Serial(param) => param.mutate(target),
+            SuiteMutator::SkipIf(param) => param.mutate(target),
+            SuiteMutator::RequiresEnv(param) => param.mutate(target),
+            SuiteMutator::Tags(param) => {
+                if cfg!(feature = "tag-names") {
+                    param.prefix(target);
+                }
+
+                match cfg!(feature = "runtime-filter") {
+                    true => param.mutate(target),
+                    false => Ok(())
+                }
+            }
         }
     }
 }
@@ -57,14 +253,48 @@ impl ToTokens for SuiteMutator {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
             SuiteMutator::Setup(param) => param.to_tokens(tokens),
-            SuiteMutator::Teardown(param) => param.to_tokens(tokens)
+            SuiteMutator::Teardown(param) => param.to_tokens(tokens),
+            SuiteMutator::Context(param) => param.to_tokens(tokens),
+            SuiteMutator::OnFailure(param) => param.to_tokens(tokens),
+            SuiteMutator::Timeout(param) => param.to_tokens(tokens),
+            SuiteMutator::Seed(param) => param.to_tokens(tokens),
+            SuiteMutator::Serial(param) => param.to_tokens(tokens),
+            SuiteMutator::SkipIf(param) => param.to_tokens(tokens),
+            SuiteMutator::RequiresEnv(param) => param.to_tokens(tokens),
+            SuiteMutator::Tags(param) => param.to_tokens(tokens)
         };
     }
 }
 
 impl SuiteMutator {
+    // Forwarded under `cfg` alone, should the fn only be a #[test_case] under such
+    fn forward(&self, target: &mut ItemFn, cfg: Option<&ParamCfg>) {
+        // #[test_case] filters by tags regardless
+        if matches!(self, SuiteMutator::Tags(tags) if tags.is_empty()) {
+            return;
+        }
+
+        let ident = Ident::new(match self {
+            SuiteMutator::Setup(_) => TestSuite::SETUP_IDENT,
+            SuiteMutator::Teardown(_) => TestSuite::TEARDOWN_IDENT,
+            SuiteMutator::Context(_) => ParamContext::IDENT,
+            SuiteMutator::OnFailure(_) => ParamOnFailure::IDENT,
+            SuiteMutator::Timeout(_) => ParamTimeout::IDENT,
+            SuiteMutator::Seed(_) => ParamSeed::IDENT,
+            SuiteMutator::Serial(_) => ParamSerial::IDENT,
+            SuiteMutator::SkipIf(_) => ParamSkipIf::IDENT,
+            SuiteMutator::RequiresEnv(_) => ParamRequiresEnv::IDENT,
+            SuiteMutator::Tags(_) => ParamTags::IDENT
+        }, Span::call_site());
+
+        match cfg {
+            Some(cfg) => target.attrs.push(cfg.cfg_attr(&[syn::parse_quote!(#ident { #self })])),
+            None => target.attrs.push(syn::parse_quote!(#[#ident { #self }]))
+        };
+    }
+
     fn new_from(function: &mut ItemFn) -> Option<SuiteMutator> {
-        for attribute in &function.attrs {
+        for (i, attribute) in function.attrs.iter().enumerate() {
             match attribute_name_to_string(attribute).as_str() {
                 TestSuite::SETUP_IDENT => {
                     return Some(
@@ -76,6 +306,13 @@ impl SuiteMutator {
                         SuiteMutator::Teardown(ParamTeardown(take(&mut function.block.stmts)))
                     );
                 },
+                // Unlike setup/teardown, the hook is called rather than spliced - so remains defined
+                ParamOnFailure::IDENT => {
+                    function.attrs[i] = syn::parse_quote!(#[cfg_attr(not(test), allow(dead_code))]);
+                    return Some(
+                        SuiteMutator::OnFailure(ParamOnFailure(function.sig.ident.clone()))
+                    );
+                },
                 _ => {}
             }
         }
@@ -103,7 +340,23 @@ impl Mutate for TestSuite {
             return Ok(());
         };
 
-        if is_test_attribute(&function.attrs) {
+        // #[test_case] functions are expanded after the suite, so their setup/teardown
+        // is forwarded for #[test_case] to apply relative to its own parameters
+        let is_test_case = function.attrs.iter().any(| attribute | {
+            attribute_name_to_string(attribute).as_str() == TestCase::SITH_TEST_IDENT
+        });
+        // As should those within `#[cfg_attr(pred, test_case(...))]`, though only under `pred`
+        let predicates: Vec<ParamCfg> = function.attrs.iter()
+            .filter_map(| attribute | split_cfg_attr(attribute, TestCase::SITH_TEST_IDENT))
+            .map(| (predicate, ..) | predicate)
+            .collect();
+
+        if is_test_case {
+            mutators.iter().for_each(| mutator | mutator.forward(function, None));
+        } else if !predicates.is_empty() {
+            let cfg: ParamCfg = ParamCfg::any(&predicates);
+            mutators.iter().for_each(| mutator | mutator.forward(function, Some(&cfg)));
+        } else if is_test_attribute(&function.attrs) {
             for mutator in mutators {
                 mutator.mutate(function)?;
             }
@@ -115,10 +368,18 @@ impl Mutate for TestSuite {
 
 impl Parse for TestSuite {
     fn parse(input: ParseStream) -> Result<Self> {
-        let Result::Ok(mut target) = input.parse::<ItemMod>() else {
+        let Result::Ok(target) = input.parse::<ItemMod>() else {
             return Err(error_spanned!("#[test_suite] can only be applied to modules", &input.span()));
         };
 
+        Self::try_from(target)
+    }
+}
+
+impl TryFrom<ItemMod> for TestSuite {
+    type Error = syn::Error;
+
+    fn try_from(mut target: ItemMod) -> Result<Self> {
         let Some(mut contents) = take(&mut target.content) else {
             return Ok( Self { name: target.ident, mutators: None, contents: None } );
         };
@@ -136,9 +397,13 @@ impl Parse for TestSuite {
             };
 
             if let Some(mutator) = SuiteMutator::new_from(item) {
+                let is_spliced = !matches!(mutator, SuiteMutator::OnFailure(_));
                 mutators.insert_unique(mutator)?;
-                contents.1.remove(i - removed_elements);
-                removed_elements += 1;
+
+                if is_spliced {
+                    contents.1.remove(i - removed_elements);
+                    removed_elements += 1;
+                }
             }
         }
 
@@ -198,7 +463,50 @@ fn is_test_attribute(attributes: &[Attribute]) -> bool {
         })
 }
 
-pub fn render_test_suite(mut test_suite: TestSuite) -> TokenStream {
+pub fn render_test_suite(params: TestSuiteParams, target: ItemMod) -> TokenStream {
+    let mut out: TokenStream = TokenStream::new();
+
+    for instance in unwrap_or_err!(params.instantiate(target)) {
+        match TestSuite::try_from(instance) {
+            Ok(mut test_suite) => {
+                if let Some(mutators) = &mut test_suite.mutators {
+                    if let Some(seed) = params.seed() {
+                        mutators.insert(SuiteMutator::Seed(seed.clone()));
+                    }
+                    if let Some(timeout) = params.timeout() {
+                        mutators.insert(SuiteMutator::Timeout(timeout.clone()));
+                    }
+                    if let Some(serial) = params.serial() {
+                        mutators.insert(SuiteMutator::Serial(serial.clone()));
+                    }
+                    if let Some(skip_if) = params.skip_if() {
+                        mutators.insert(SuiteMutator::SkipIf(skip_if.clone()));
+                    }
+                    if let Some(requires_env) = params.requires_env() {
+                        mutators.insert(SuiteMutator::RequiresEnv(requires_env.clone()));
+                    }
+                    // Under `runtime-filter`, every #[test] is filtered by tags - as #[test_case]s
+                    // are, untagged or not. The context is only bound should a test refer to such
+                    if params.tags().is_some() || cfg!(feature = "runtime-filter") {
+                        mutators.insert(SuiteMutator::Tags(params.tags().cloned().unwrap_or_default()));
+                    }
+                    mutators.insert(SuiteMutator::Context(ParamContext {
+                        suite: Some(test_suite.name.clone()),
+                        tags: params.tags().cloned().unwrap_or_default(),
+                        ..ParamContext::default()
+                    }));
+                }
+
+                out.append_all(render_suite_instance(test_suite));
+            },
+            Err(e) => out.append_all(e.to_compile_error())
+        };
+    }
+
+    out
+}
+
+fn render_suite_instance(mut test_suite: TestSuite) -> TokenStream {
     let Option::Some(mut contents) = take(&mut test_suite.contents) else {
         return test_suite.to_token_stream();
     };
@@ -295,6 +603,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_retains_on_failure_function() {
+        assert_eq_parsed!(
+            syn::parse2::<TestSuite>(quote!{
+                mod my_suite {
+                    #[on_failure]
+                    fn dump(failure: &Failure) {}
+                }
+            }),
+            Ok(TestSuite {
+                name: parse_quote!(my_suite),
+                mutators: Some(
+                    Mutators::from(
+                        [SuiteMutator::OnFailure(ParamOnFailure(parse_quote!(dump)))]
+                    )
+                ),
+                contents: Some(vec![
+                    parse_quote!{
+                        #[cfg_attr(not(test), allow(dead_code))]
+                        fn dump(failure: &Failure) {}
+                    }
+                ])
+            })
+        );
+    }
+
     #[test]
     fn parse_works_with_no_setup_and_teardown() {
         assert_eq_parsed!(
@@ -435,10 +769,9 @@ mod tests {
         assert_eq_tokens!(
             items[4], quote!{
                 #[test_case]
-                fn two() {
-                    let a = 123;
-                    let b = 456;
-                }
+                #[setup { let a = 123; }]
+                #[teardown { let b = 456; }]
+                fn two() {}
             }
         );
 
@@ -453,6 +786,321 @@ mod tests {
         );
     }
 
+    #[test]
+    fn params_parse_for_types() {
+        assert_eq_parsed!(
+            syn::parse2::<TestSuiteParams>(quote!(for_types(T = [A, B]))),
+            Ok(quote!(T = [A, B]))
+        );
+    }
+
+    #[test]
+    fn params_parse_seed() {
+        let params: TestSuiteParams = parse_quote!(seed, for_types(T = [A]));
+
+        assert!(params.seed().is_some());
+        assert_eq_tokens!(params, quote!(T = [A] seed));
+    }
+
+    #[test]
+    fn params_parse_serial() {
+        let params: TestSuiteParams = parse_quote!(serial(env));
+
+        assert!(params.serial().is_some());
+        assert_eq_tokens!(params, quote!(serial(env)));
+    }
+
+    #[test]
+    fn params_parse_skip_conditions() {
+        let params: TestSuiteParams = parse_quote!(skip_if(cfg!(windows), "unix only"), requires_env("DATABASE_URL"));
+
+        assert!(params.skip_if().is_some());
+        assert!(params.requires_env().is_some());
+        assert_eq_tokens!(params, quote!(cfg!(windows), "unix only" "DATABASE_URL"));
+    }
+
+    #[test]
+    fn params_parse_tags() {
+        let params: TestSuiteParams = parse_quote!(tags(db, slow));
+
+        assert!(params.tags().is_some());
+        assert_eq_tokens!(params, quote!(db, slow));
+    }
+
+    #[test]
+    fn mutate_filters_tests_by_tags() {
+        let suite = TestSuite {
+            name: parse_quote!(my_suite),
+            mutators: Some(Mutators::from([SuiteMutator::Tags(parse_quote!(db))])),
+            contents: None
+        };
+
+        let mut items: [Item; 2] = [
+            parse_quote!(#[test] fn one() {}),
+            parse_quote!(#[test_case] fn two() {})
+        ];
+
+        items.iter_mut().for_each(| item |
+            assert_eq_mutate!(suite, item, Ok(()))
+        );
+
+        let name: &str = if cfg!(feature = "tag-names") { "tag_db__one" } else { "one" };
+        let filter = match cfg!(feature = "runtime-filter") {
+            true => quote!{
+                if ::sith_runtime::filter::is_filtered(::core::module_path!(), #name, &["db"]) {
+                    return ::sith_runtime::skip::Skipped::skipped();
+                }
+            },
+            false => quote!()
+        };
+
+        if cfg!(feature = "tag-names") {
+            assert_eq_tokens!(
+                items[0], quote!{
+                    #[test]
+                    #[allow(non_snake_case)]
+                    fn tag_db__one() {
+                        #filter
+                    }
+                }
+            );
+        } else {
+            assert_eq_tokens!(
+                items[0], quote!{
+                    #[test]
+                    fn one() {
+                        #filter
+                    }
+                }
+            );
+        }
+
+        assert_eq_tokens!(
+            items[1], quote!{
+                #[test_case]
+                #[tags { db }]
+                fn two() {}
+            }
+        );
+    }
+
+    #[test]
+    fn params_parse_timeout() {
+        let params: TestSuiteParams = parse_quote!(timeout = "5s");
+
+        assert!(params.timeout().is_some());
+        assert_eq_tokens!(params, quote!("5s"));
+    }
+
+    #[test]
+    fn params_parse_returns_error_on_invalid_timeout() {
+        assert_eq_parsed!(
+            syn::parse2::<TestSuiteParams>(quote!(timeout = "soon")),
+            Err(error_spanned!("timeout: expected a duration, i.e. \"2s\" or \"500ms\""))
+        );
+    }
+
+    #[test]
+    fn mutate_binds_context_when_invoked_by_setup() {
+        let suite = TestSuite {
+            name: parse_quote!(my_suite),
+            mutators: Some(
+                Mutators::from(
+                    [
+                        SuiteMutator::Context(ParamContext {
+                            suite: Some(parse_quote!(my_suite)),
+                            ..ParamContext::default()
+                        }),
+                        SuiteMutator::Setup(ParamSetup(vec![
+                            parse_quote!(let dir = current_test!().name();)
+                        ]))
+                    ]
+                )
+            ),
+            contents: None
+        };
+
+        let mut items: [Item; 2] = [
+            parse_quote!(#[test] fn one() {}),
+            parse_quote!(#[test_case] fn two() {})
+        ];
+
+        items.iter_mut().for_each(| item |
+            assert_eq_mutate!(suite, item, Ok(()))
+        );
+
+        assert_eq_tokens!(
+            items[0], quote!{
+                #[test]
+                fn one() {
+                    let __sith_context = ::sith_runtime::TestContext::new(
+                        ::core::module_path!(), "one", ::core::option::Option::Some("my_suite"),
+                        ::core::option::Option::None, 0usize, &[], &[]
+                    );
+                    let dir = current_test!(__sith_context).name();
+                }
+            }
+        );
+
+        assert_eq_tokens!(
+            items[1], quote!{
+                #[test_case]
+                #[setup { let dir = current_test!().name(); }]
+                #[suite { my_suite }]
+                fn two() {}
+            }
+        );
+    }
+
+    #[test]
+    fn mutate_binds_seed_before_setup() {
+        let suite = TestSuite {
+            name: parse_quote!(my_suite),
+            mutators: Some(
+                Mutators::from(
+                    [
+                        SuiteMutator::Seed(ParamSeed(parse_quote!(seed))),
+                        SuiteMutator::Setup(ParamSetup(vec![
+                            parse_quote!(let a = seed;)
+                        ]))
+                    ]
+                )
+            ),
+            contents: None
+        };
+
+        let mut items: [Item; 2] = [
+            parse_quote!(#[test] fn one() {}),
+            parse_quote!(#[test_case] fn two() {})
+        ];
+
+        items.iter_mut().for_each(| item |
+            assert_eq_mutate!(suite, item, Ok(()))
+        );
+
+        assert_eq_tokens!(
+            items[0], quote!{
+                #[test]
+                fn one() {
+                    let __sith_seed = ::sith_runtime::seed::Seed::new("one");
+                    let seed: u64 = __sith_seed.value();
+                    __sith_seed.check((| | {
+                        let a = seed;
+                    })())
+                }
+            }
+        );
+
+        assert_eq_tokens!(
+            items[1], quote!{
+                #[test_case]
+                #[setup { let a = seed; }]
+                #[seed { seed }]
+                fn two() {}
+            }
+        );
+    }
+
+    #[test]
+    fn mutate_locks_serial_before_seed() {
+        let suite = TestSuite {
+            name: parse_quote!(my_suite),
+            mutators: Some(
+                Mutators::from(
+                    [
+                        SuiteMutator::Serial(parse_quote!(serial(env))),
+                        SuiteMutator::Seed(ParamSeed(parse_quote!(seed)))
+                    ]
+                )
+            ),
+            contents: None
+        };
+
+        let mut items: [Item; 2] = [
+            parse_quote!(#[test] fn one() {}),
+            parse_quote!(#[test_case] fn two() {})
+        ];
+
+        items.iter_mut().for_each(| item |
+            assert_eq_mutate!(suite, item, Ok(()))
+        );
+
+        assert_eq_tokens!(
+            items[0], quote!{
+                #[test]
+                fn one() {
+                    let __sith_serial = ::sith_runtime::serial::lock("env");
+                    let __sith_seed = ::sith_runtime::seed::Seed::new("one");
+                    let seed: u64 = __sith_seed.value();
+                    __sith_seed.check((| | {})())
+                }
+            }
+        );
+
+        assert_eq_tokens!(
+            items[1], quote!{
+                #[test_case]
+                #[seed { seed }]
+                #[serial { serial(env) }]
+                fn two() {}
+            }
+        );
+    }
+
+    #[test]
+    fn mutate_forwards_to_cfg_attr_test_cases_under_predicate() {
+        let suite = TestSuite {
+            name: parse_quote!(my_suite),
+            mutators: Some(
+                Mutators::from([SuiteMutator::Setup(ParamSetup(vec![parse_quote!(let a = 1;)]))])
+            ),
+            contents: None
+        };
+
+        let mut item: Item = parse_quote!{
+            #[cfg_attr(unix, test_case(one))]
+            #[cfg_attr(windows, test_case(two))]
+            fn test() {}
+        };
+
+        assert_eq_mutate!(suite, &mut item, Ok(()));
+        assert_eq_tokens!(
+            item, quote!{
+                #[cfg_attr(unix, test_case(one))]
+                #[cfg_attr(windows, test_case(two))]
+                #[cfg_attr(any(unix, windows), setup { let a = 1; })]
+                fn test() {}
+            }
+        );
+    }
+
+    #[test]
+    fn params_parse_returns_error_on_unrecognized_arg() {
+        assert_eq_parsed!(
+            syn::parse2::<TestSuiteParams>(quote!(foobar)),
+            Err(error_spanned!("unrecognized arg"))
+        );
+    }
+
+    #[test]
+    fn params_instantiate_one_module_per_type() {
+        let params: TestSuiteParams = parse_quote!(for_types(T = [A, B]));
+        let instances = params.instantiate(parse_quote!(mod suite { type Inner = T; })).unwrap();
+
+        assert_eq!(instances.len(), 2);
+        assert_eq_tokens!(instances[0], quote!(mod suite_a { type Inner = A; }));
+        assert_eq_tokens!(instances[1], quote!(mod suite_b { type Inner = B; }));
+    }
+
+    #[test]
+    fn params_instantiate_is_noop_without_for_types() {
+        let params: TestSuiteParams = parse_quote!();
+        let instances = params.instantiate(parse_quote!(mod suite { type Inner = T; })).unwrap();
+
+        assert_eq!(instances.len(), 1);
+        assert_eq_tokens!(instances[0], quote!(mod suite { type Inner = T; }));
+    }
+
     #[test]
     fn attribute_only_applies_to_modules() {
         assert_eq_parsed!(
@@ -26,38 +26,57 @@
 //! }
 //! ```
 //! 
+//! `verbatim(...)` inputs may be pasted into identifiers via `[< ... >]`. Pastes
+//! aren't valid Rust syntax, so are only accepted within macro invocations:
+//! 
+//! ```
+//! use sith::test_case;
+//! 
+//! #[test_case(with(verbatim(len)))]
+//! fn pasted(r#field: _) {
+//!     assert_eq!(vec![1].[< r#field >](), 1);
+//! }
+//! ```
+//! 
+//! ```compile_fail
+//! use sith::test_case;
+//! 
+//! #[test_case(with(verbatim(len)))]
+//! fn pasted(r#field: _) {
+//!     let len = vec![1].[< r#field >]();
+//! }
+//! ```
+//! 
 //! ## `#[test_suite]`
 //! 
 //! Tests may be aggregated into modules or `suites`. Currently, modules
 //! affixed with the `#[test_suite]` attribute may define a common set of 
 //! `setup`/`teardown` routines: I.e, pre-test configuration and post-test cleanup.
+//! With `#[test_suite(seed)]`, each test is bound a reproducible `seed`, reported
+//! should the test fail.
 //! 
 //! ### Example
 //! 
 //! ```
-//! #[test_suite]
+//! use sith::test_suite;
+//!
+//! #[test_suite(seed)]
 //! mod suite {
 //!     use sith::test_case;
-//!     use std::io::Write;
 //!
 //!     #[setup]
 //!     fn setup() {
-//!         let handler = std::panic::take_hook();
-//!         std::panic::set_hook(Box::new(move | info | {
-//!            let _ = std::io::stderr().write_fmt(format_args!("failed with seed: {}\n", SEED));
-//!             handler(info);
-//!         })); 
+//!         let offset = seed % 16;
 //!     }
 //! 
 //!     #[teardown]
 //!     fn teardown() {
-//!         // Re-register the default panic hook
-//!         let _ = std::panic::take_hook();
+//!         assert!(offset < 16);
 //!     }
 //! 
 //!     #[test_case]
 //!     fn unparameterized() {
-//!         println!("Hello from a unparameterized test!");
+//!         println!("Hello from a unparameterized test with seed {}!", seed);
 //!     }
 //! }
 //! 
@@ -93,9 +112,24 @@ macro_rules! parse_token_stream {
 }
 
 #[proc_macro_attribute]
-pub fn test_suite(_: TokenStream, target: TokenStream) -> TokenStream {
-    let test_suite: TestSuite = parse_token_stream!(target => TestSuite);
-    render_test_suite(test_suite).into()
+pub fn test_suite(attr_args: TokenStream, target: TokenStream) -> TokenStream {
+    use syn::ItemMod;
+
+    let params: TestSuiteParams = parse_token_stream!(attr_args => TestSuiteParams);
+    let test_suite: ItemMod = parse_token_stream!(target => ItemMod, "#[test_suite] can only be applied to modules");
+    render_test_suite(params, test_suite).into()
+}
+
+/// Expands to the [`TestContext`](https://docs.rs/sith_runtime/latest/sith_runtime/context/struct.TestContext.html)
+/// of the running test - its name, suite, case, inputs & tags. Available within `#[test_case]`s,
+/// `#[test]`s of a `#[test_suite]`, and the `#[setup]`/`#[teardown]` thereof.
+///
+/// Invocations are bound by the test's attribute as it's expanded, so must be written within the
+/// test itself - closures and nested macros included. Elsewhere, i.e. within a helper function
+/// called by the test, `current_test!()` fails to compile; pass the context to such instead.
+#[proc_macro]
+pub fn current_test(input: TokenStream) -> TokenStream {
+    render_current_test(input.into()).into()
 }
 
 #[proc_macro_attribute]
@@ -0,0 +1,56 @@
+// Filtered tests are run apart, as `SITH_SHARD` & `SITH_TAGS` are read from the whole process
+#![cfg(feature = "runtime-filter")]
+
+use sith::test_case;
+
+// Untagged, so as to keep its name under `tag-names`
+#[test_case]
+#[should_panic(expected = "overflow")]
+fn overflows() {
+    panic!("overflow");
+}
+
+// Runs the tests matching `filter` apart from this process, as `SITH_SHARD` & `SITH_TAGS`
+// apply to every test thereof - returning whether all passed, & their uncaptured output
+fn run_filtered(filter: &str, env: &[(&str, &str)]) -> (bool, String) {
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args([filter, "--test-threads=1"])
+        .env_remove("SITH_SHARD")
+        .env_remove("SITH_TAGS")
+        .envs(env.iter().copied())
+        .output()
+        .unwrap();
+
+    (output.status.success(), String::from_utf8_lossy(&output.stderr).into_owned())
+}
+
+#[test]
+fn filtered_should_panic_tests_panic_as_expected() {
+    // Within exactly one of the shards, the test runs - and is sharded out of the others
+    let mut sharded_out: usize = 0;
+    for shard in 1..=4 {
+        let shard: String = format!("{}/4", shard);
+        let (is_success, stderr) = run_filtered("overflows", &[("SITH_SHARD", &shard)]);
+
+        assert!(is_success, "shard {}: {}", shard, stderr);
+        sharded_out += usize::from(stderr.contains(&format!("`overflows` skipped: sharded out by SITH_SHARD={}", shard)));
+    }
+    assert_eq!(sharded_out, 3);
+
+    let (is_success, stderr) = run_filtered("overflows", &[("SITH_TAGS", "slow")]);
+    assert!(is_success, "{}", stderr);
+    assert!(stderr.contains("`overflows` skipped: untagged don't match SITH_TAGS=slow"), "{}", stderr);
+}
+
+#[test_case(tagged, tags(slow, io))]
+#[test_case(untagged)]
+fn labelled() {}
+
+#[test]
+fn tags_filter_tests_by_env() {
+    let (is_success, stderr) = run_filtered("labelled_", &[("SITH_TAGS", "!slow")]);
+
+    assert!(is_success, "{}", stderr);
+    assert!(stderr.contains("labelled_tagged` skipped: tags `slow`, `io` don't match SITH_TAGS=!slow"), "{}", stderr);
+    assert!(!stderr.contains("labelled_untagged` skipped"), "{}", stderr);
+}
@@ -20,4 +20,461 @@ fn supports_multiple_declarations_with_different_types(input: _) {
 #[test_case(two, with(0f64, verbatim(f64)))]
 fn verbatim_and_with_work_together(input: _, r#type: _) {
     assert_eq!(input, r#type::default());
-}
\ No newline at end of file
+}
+
+trait Codec: Sized + PartialEq + core::fmt::Debug {
+    fn encode(&self) -> Vec<u8>;
+    fn decode(bytes: &[u8]) -> Self;
+}
+
+impl Codec for u8 {
+    fn encode(&self) -> Vec<u8> { self.to_le_bytes().to_vec() }
+    fn decode(bytes: &[u8]) -> Self { Self::from_le_bytes([bytes[0]]) }
+}
+
+impl Codec for u64 {
+    fn encode(&self) -> Vec<u8> { self.to_le_bytes().to_vec() }
+    fn decode(bytes: &[u8]) -> Self { Self::from_le_bytes(bytes.try_into().unwrap()) }
+}
+
+#[test_case(u8, types(T = u8))]
+#[test_case(u64, types(T = u64))]
+fn types_instantiate_generic_parameters<T: Codec + Default>() {
+    assert_eq!(T::decode(&T::default().encode()), T::default());
+}
+
+#[test_case(u8, types(T = u8), with(u8::MAX))]
+#[test_case(u64, types(T = u64), with(u64::MAX))]
+fn types_and_with_work_together<T: Codec>(input: T) {
+    assert_eq!(T::decode(&input.encode()), input);
+}
+
+#[test_case(str, types(T = str), with("abc"))]
+#[test_case(slice, types(T = [u8]), with(&[1, 2, 3]))]
+fn types_instantiate_unsized_parameters<T: ?Sized>(input: &T) {
+    assert_eq!(std::mem::size_of_val(input), 3);
+}
+
+#[test_case(four, types(N = 4))]
+#[test_case(sixteen, types(N = 16))]
+fn types_instantiate_const_generic_parameters<const N: usize>() {
+    assert_eq!([0u8; N].len(), N);
+}
+
+#[test_case(int, with(1, "1"))]
+#[test_case(float, with(2.5, String::from("2.5")))]
+fn supports_impl_trait_inputs(value: impl core::fmt::Display, expected: impl AsRef<str>) {
+    assert_eq!(value.to_string(), expected.as_ref());
+}
+
+#[test_case(usize, with(vec![1usize, 2], 3usize))]
+#[test_case(char, with(vec!['a', 'b'], 'c'))]
+fn supports_generic_inputs<T: PartialOrd>(values: Vec<T>, limit: T) {
+    assert!(values.iter().all(| value | *value < limit));
+}
+
+struct Point {
+    x: isize,
+    y: isize
+}
+
+struct Marker;
+
+#[test_case(tuple, with((1, 2), Point { x: 1, y: 2 }, Marker))]
+fn supports_destructured_inputs((a, b): (isize, isize), Point { x, y }: Point, _: Marker) {
+    assert_eq!((a, b), (x, y));
+}
+
+#[test_case(mutable, with(mut (1, 2)))]
+fn supports_mut_overrides_on_destructured_inputs((a, b): (usize, usize)) {
+    a += 1;
+    b += 1;
+    assert_eq!((a, b), (2, 3));
+}
+
+#[test_case(derived, with(10, size * 2))]
+fn later_inputs_may_reference_earlier_inputs(size: usize, doubled: usize) {
+    assert_eq!(doubled, 20);
+}
+
+#[test_case(u8, with(verbatim(u8), 255))]
+#[test_case(u16, with(verbatim(u16), 65535))]
+fn verbatim_substitutes_within_signature(r#type: _, max: r#type) -> Result<(), <r#type as TryFrom<u32>>::Error> {
+    assert_eq!(<r#type>::try_from(max as u32)?, <r#type>::MAX);
+    Ok(())
+}
+
+struct Dimensions {
+    width: u32,
+    height: u32
+}
+
+impl Dimensions {
+    fn get_width(&self) -> u32 {
+        self.width
+    }
+
+    fn get_height(&self) -> u32 {
+        self.height
+    }
+}
+
+#[test_case(width, with(verbatim(width), 4))]
+#[test_case(height, with(verbatim(height), 3))]
+fn pastes_identifiers_from_verbatim(r#field: _, expected: u32) {
+    let dimensions = Dimensions { width: 4, height: 3 };
+    assert_eq!(dimensions.[< get_ r#field >](), expected);
+}
+
+#[test_case(u8, with(verbatim(8), 255))]
+#[test_case(u16, with(verbatim(16), 65535))]
+fn pastes_literals_from_verbatim(r#width: _, expected: u64) {
+    assert_eq!([< u r#width >]::MAX as u64, expected);
+}
+
+#[derive(Debug, PartialEq)]
+enum Channel {
+    Red,
+    Green,
+    Blue
+}
+
+impl Channel {
+    fn index(&self) -> usize {
+        match self {
+            Channel::Red => 0,
+            Channel::Green => 1,
+            Channel::Blue => 2
+        }
+    }
+}
+
+#[test_case(with(each(0..=8)))]
+fn each_expands_ranges(value: u8) {
+    assert!(value <= 8);
+}
+
+#[test_case(with(each([Channel::Red, Channel::Green, Channel::Blue]), each(0..2)))]
+fn each_combines_as_product(channel: Channel, offset: usize) {
+    assert!(channel.index() + offset < 4);
+}
+
+#[test_case(with(zip(each([Channel::Red, Channel::Green, Channel::Blue]), each(0..3))))]
+fn each_pairs_when_zipped(channel: Channel, expected: usize) {
+    assert_eq!(channel.index(), expected);
+}
+
+#[test]
+fn each_generates_named_tests() {
+    each_expands_ranges_0();
+    each_expands_ranges_8();
+    each_combines_as_product_channel_blue_1();
+    each_pairs_when_zipped_channel_green_1();
+}
+
+#[test_case(pairwise(channel = [Channel::Red, Channel::Green, Channel::Blue], offset = 0..3, scale = [1, 2], seed = 3))]
+fn pairwise_covers_value_pairs(channel: Channel, offset: usize, scale: usize) {
+    assert!((channel.index() + offset) * scale < 10);
+}
+
+#[test]
+fn pairwise_generates_value_named_tests() {
+    // Names derive from values, so remain stable between builds
+    let _: fn() = pairwise_covers_value_pairs_channel_red_0_1;
+}
+
+#[test_case(property(cases = 64))]
+fn property_generates_inputs_from_types(value: u32, bytes: Vec<u8>, label: Option<String>) {
+    assert_eq!(value.to_le_bytes().len(), 4);
+    assert!(bytes.len() <= 32);
+    assert!(label.map_or(true, | label | label.chars().count() <= 32));
+}
+
+#[test_case(property(cases = 64), with(sith_runtime::any::<u16>(), 1..10u16))]
+fn property_accepts_strategies(value: u16, divisor: u16) {
+    assert!(value / divisor <= value);
+}
+
+#[test_case(property(cases = 64), with(_, 1..10u16))]
+#[should_panic(expected = "minimal input: (value, divisor) = (1000, 1)")]
+fn property_shrinks_failing_inputs(value: u16, divisor: u16) {
+    assert!(value < 1000 * divisor);
+}
+
+#[test_case(seed, with(seed.wrapping_add(1)))]
+fn seed_is_bound_before_inputs(next: u64) {
+    assert_eq!(next, seed.wrapping_add(1));
+}
+
+#[test_case(seed)]
+#[should_panic(expected = "seeded failure")]
+fn seed_is_reported_on_panic() {
+    let _ = seed;
+    panic!("seeded failure");
+}
+
+#[test_case(seed)]
+#[ignore = "fails by design, run by seed_is_reported_on_error"]
+fn seeded_error() -> Result<(), String> {
+    Err(format!("seeded error {}", seed))
+}
+
+#[test]
+fn seed_is_reported_on_error() {
+    // Run apart from this process, so as to capture the report
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args(["seeded_error", "--exact", "--ignored", "--nocapture"])
+        .env("SITH_SEED", "1234")
+        .env_remove("SITH_SHARD")
+        .env_remove("SITH_TAGS")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("test `seeded_error` failed with seed: 1234 (reproduce with SITH_SEED=1234)"), "{}", stderr);
+}
+
+struct Opaque(u8);
+
+#[test_case(two, with(2, Opaque(2)))]
+#[ignore = "fails by design, run by failing_case_reports_inputs"]
+fn failing_case(value: u8, opaque: Opaque) {
+    assert_eq!(value, opaque.0);
+    panic!("row mismatch");
+}
+
+#[cfg(feature = "case-reports")]
+#[test]
+fn failing_case_reports_inputs() {
+    // Run apart from this process, so as to capture the report
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args(["failing_case_two", "--exact", "--ignored", "--nocapture"])
+        .env_remove("SITH_SHARD")
+        .env_remove("SITH_TAGS")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("test case `failing_case_two` failed (tests/test_case.rs:"), "{}", stderr);
+    assert!(stderr.contains("\n    value: 2 = 2\n"), "{}", stderr);
+    // Spans multiple tokens, so only has a source where such may be joined (i.e. on nightly)
+    assert!(stderr.lines().any(| line | {
+        line.starts_with("    opaque") && line.ends_with(" = <test_case::Opaque>")
+    }), "{}", stderr);
+}
+
+#[test_case(timeout = "5s", with(2))]
+fn timeout_passes_within_deadline(value: u8) {
+    assert_eq!(value * 2, 4);
+}
+
+#[test_case(timeout = "5s", with("42"))]
+fn timeout_supports_result_outputs(value: &str) -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(value.parse::<u8>()?, 42);
+    Ok(())
+}
+
+#[test_case(timeout = "10ms")]
+#[should_panic(expected = "test `timeout_fails_hanging_case` timed out after")]
+fn timeout_fails_hanging_case() {
+    std::thread::sleep(std::time::Duration::from_secs(5));
+}
+
+static FLAKY_ATTEMPTS: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+
+#[test_case(retries = 2, with(FLAKY_ATTEMPTS.fetch_add(1, std::sync::atomic::Ordering::SeqCst)))]
+fn retries_rerun_inputs_until_passing(attempt: u32) {
+    assert!(attempt >= 2, "flaked on attempt {}", attempt);
+}
+
+#[test_case(retries = 1)]
+#[should_panic(expected = "always fails")]
+fn retries_fail_once_exhausted() {
+    panic!("always fails");
+}
+
+thread_local!(static REPEATED_RUNS: std::cell::Cell<u32> = const { std::cell::Cell::new(0) });
+
+#[test_case(repeat(100))]
+fn repeat_runs_body_per_iteration() {
+    REPEATED_RUNS.with(| runs | runs.set(runs.get() + 1));
+}
+
+#[test_case(repeat(3))]
+#[should_panic(expected = "fails on third")]
+fn repeat_fails_on_any_iteration() {
+    thread_local!(static RUN: std::cell::Cell<u32> = const { std::cell::Cell::new(0) });
+    let run = RUN.with(| run | { run.set(run.get() + 1); run.get() });
+    assert!(run < 3, "fails on third");
+}
+
+#[test_case(repeat(expand = 3), with(each([1, 2])))]
+fn repeat_expands(value: u8) {
+    REPEATED_RUNS.with(| runs | runs.set(runs.get() + u32::from(value)));
+}
+
+// Set by `SITH_SHARD`/`SITH_TAGS`, which filter out the tests driven by others
+fn is_filtering() -> bool {
+    std::env::var_os("SITH_SHARD").is_some() || std::env::var_os("SITH_TAGS").is_some()
+}
+
+#[test_case(skip_if(is_filtering(), "drives tests which may be filtered out"))]
+fn repeat_runs_as_many_times_as_given() {
+    repeat_runs_body_per_iteration();
+    assert_eq!(REPEATED_RUNS.with(| runs | runs.take()), 100);
+
+    repeat_expands_1_0();
+    repeat_expands_1_1();
+    repeat_expands_1_2();
+    repeat_expands_2_0();
+    repeat_expands_2_1();
+    repeat_expands_2_2();
+    assert_eq!(REPEATED_RUNS.with(| runs | runs.take()), 9);
+}
+
+static SERIAL_RUNNING: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+#[test_case(one, serial(env))]
+#[test_case(two, serial(env))]
+#[test_case(three, serial(env))]
+fn serial_cases_run_alone() {
+    assert!(!SERIAL_RUNNING.swap(true, std::sync::atomic::Ordering::SeqCst), "serial tests overlapped");
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    SERIAL_RUNNING.store(false, std::sync::atomic::Ordering::SeqCst);
+}
+
+#[test_case(skip_if(true, "always skipped"))]
+fn skip_if_returns_before_body() {
+    panic!("should have been skipped");
+}
+
+#[test_case(skip_if(1 + 1 == 3))]
+#[should_panic(expected = "not skipped")]
+fn skip_if_runs_body_otherwise() {
+    panic!("not skipped");
+}
+
+#[test_case(requires_env("SITH_TEST_NEVER_SET"))]
+fn requires_env_skips_when_unset() -> Result<(), String> {
+    Err(String::from("should have been skipped"))
+}
+
+#[test_case(skip_if(true))]
+#[should_panic(expected = "overflow")]
+fn skip_if_panics_as_expected_by_should_panic() {
+    unreachable!("should have been skipped");
+}
+
+#[test_case(requires_env("SITH_TEST_NEVER_SET"))]
+#[should_panic]
+fn requires_env_panics_as_expected_by_should_panic() {
+    unreachable!("should have been skipped");
+}
+
+#[test_case(unix_paths, with("/tmp"), cfg(unix))]
+#[test_case(never_compiled, with(does_not_exist::PATH), cfg(any()))]
+#[test_case(always, with("."), cfg(all()))]
+fn cfg_compiles_enabled_cases(path: &str) {
+    assert!(!path.is_empty());
+}
+
+#[test_case(first)]
+#[cfg_attr(all(), test_case(second))]
+#[cfg_attr(any(), test_case(third, with(does_not_exist::VALUE)))]
+fn cfg_attr_cases() {}
+
+#[test]
+fn cfg_attr_cases_are_generated() {
+    cfg_attr_cases_first();
+    cfg_attr_cases_second();
+}
+
+#[test_case(documented, attrs(#[doc = "A documented case"], #[allow(clippy::eq_op)]))]
+#[test_case(plain)]
+fn attrs_are_passed_through() {
+    assert_eq!(1, 1);
+}
+
+#[test_case(expected_to_panic, attrs(#[should_panic(expected = "panics")]))]
+fn attrs_apply_to_their_case_alone() {
+    panic!("panics");
+}
+
+#[test_case(xfail("issue 142"))]
+fn xfail_passes_when_body_panics() {
+    panic!("still broken")
+}
+
+#[test_case(xfail("issue 143"))]
+fn xfail_passes_when_body_errors() -> Result<(), String> {
+    Err(String::from("still broken"))
+}
+
+#[test_case(xfail("issue 144"))]
+#[should_panic(expected = "unexpectedly passed, remove xfail (issue 144)")]
+fn xfail_fails_when_body_passes() {}
+
+#[test_case(tagged, tags(slow, io))]
+#[test_case(untagged)]
+fn tags_label_cases() {
+    let tags: &[&str] = match sith::current_test!().case() {
+        Some("tagged") => &["slow", "io"],
+        _ => &[]
+    };
+    assert_eq!(sith::current_test!().tags(), tags);
+}
+
+#[cfg(feature = "tag-names")]
+#[test]
+fn tags_prefix_names() {
+    tag_slow__tag_io__tags_label_cases_tagged();
+    tags_label_cases_untagged();
+}
+
+#[test_case(with(each(0..64u8)), sample(4))]
+fn sampled(value: u8) {
+    assert!(value < 64);
+}
+
+#[test]
+fn sample_skips_all_but_a_subset() {
+    // Run apart from this process, as `SITH_SAMPLE` applies to every test thereof
+    let sampled_out = | env: &[(&str, &str)] | {
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["sampled_", "--test-threads=1"])
+            .env_remove("SITH_SAMPLE")
+            .env_remove("SITH_SAMPLE_SEED")
+            .env_remove("SITH_SHARD")
+            .env_remove("SITH_TAGS")
+            .envs(env.iter().copied())
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+
+        assert!(output.status.success(), "{}", stderr);
+        stderr.lines().filter(| line | line.contains("skipped: sampled out")).count()
+    };
+
+    assert_eq!(sampled_out(&[]), 60);
+    assert_eq!(sampled_out(&[("SITH_SAMPLE", "0.5")]), 32);
+    assert_eq!(sampled_out(&[("SITH_SAMPLE", "1")]), 0);
+}
+
+#[test_case(first, with(1))]
+#[test_case(second, with(1 + 1))]
+fn current_test_describes_case(value: u8) {
+    let context = sith::current_test!();
+
+    assert_eq!(context.suite(), None);
+    assert_eq!(context.index(), value as usize - 1);
+    assert_eq!(context.inputs(), [("value", if value == 1 { "1" } else { "1 + 1" })]);
+    assert_eq!(context.qualified_name(), format!("current_test_describes_case_{}", context.case().unwrap()));
+}
+
+#[test_case(int, with(5u8, 5u64))]
+#[test_case(char, with('a', 'a' as u32))]
+fn generic_inputs_relate_by_bounds<T: Into<U> + Copy, U: PartialEq + core::fmt::Debug>(value: T, expected: U) {
+    assert_eq!(expected, value.into());
+}
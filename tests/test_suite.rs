@@ -1,4 +1,6 @@
-use sith::test_suite;
+use sith::{
+    test_case, test_suite
+};
 
 #[test_suite]
 mod supports_rustc_test {
@@ -67,4 +69,335 @@ mod supports_wasm_bindgen_test {
 #[test_suite]
 mod ignores_empty_modules {
     
-}
\ No newline at end of file
+}
+
+trait Queue {
+    fn new() -> Self;
+    fn enqueue(&mut self, value: usize);
+    fn size(&self) -> usize;
+}
+
+impl Queue for Vec<usize> {
+    fn new() -> Self { Vec::new() }
+    fn enqueue(&mut self, value: usize) { self.push(value) }
+    fn size(&self) -> usize { self.len() }
+}
+
+impl Queue for std::collections::VecDeque<usize> {
+    fn new() -> Self { std::collections::VecDeque::new() }
+    fn enqueue(&mut self, value: usize) { self.push_back(value) }
+    fn size(&self) -> usize { self.len() }
+}
+
+#[test_suite(for_types(T = [Vec<usize>, std::collections::VecDeque<usize>]))]
+mod supports_for_types {
+    use super::Queue;
+    use sith::test_case;
+
+    #[setup]
+    fn setup() {
+        let mut queue = <T as Queue>::new();
+    }
+
+    #[test_case]
+    fn enqueue() {
+        queue.enqueue(1);
+        assert_eq!(queue.size(), 1);
+    }
+
+    // Declares its own `T`, so isn't substituted
+    fn filled<T: Queue>(size: usize) -> T {
+        let mut queue = T::new();
+        (0..size).for_each(| value | queue.enqueue(value));
+        queue
+    }
+
+    #[test_case]
+    fn enqueue_many() {
+        queue.enqueue(filled::<T>(3).size());
+        assert_eq!(queue.size(), 1);
+    }
+}
+
+#[test_suite]
+mod inputs_may_reference_setup_bindings {
+    use sith::test_case;
+
+    #[setup]
+    fn setup() {
+        let base = 10;
+    }
+
+    #[test_case(with(base + 1, base + offset))]
+    fn inner(offset: usize, total: usize) {
+        assert_eq!(total, 21);
+    }
+}
+
+#[test_suite(seed)]
+mod supports_seed {
+    use sith::test_case;
+
+    #[setup]
+    fn setup() {
+        let seeded: u64 = seed;
+    }
+
+    #[test]
+    fn rustc_test() {
+        assert_eq!(seeded, seed);
+    }
+
+    #[test_case(with(seed))]
+    fn test_case(value: u64) {
+        assert_eq!(value, seeded);
+    }
+
+    #[test_case(seed)]
+    fn test_case_seed() {
+        assert_eq!(seeded, seed);
+    }
+}
+
+#[test_suite]
+mod supports_on_failure {
+    use sith::test_case;
+    use sith_runtime::Failure;
+
+    thread_local! {
+        pub static FAILURES: std::cell::RefCell<Vec<Failure>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    #[on_failure]
+    fn record(failure: &Failure) {
+        FAILURES.with(| failures | failures.borrow_mut().push(failure.clone()));
+    }
+
+    #[setup]
+    fn setup() {
+        let limit: u8 = 2;
+    }
+
+    #[test_case(with(3, "three"))]
+    #[should_panic(expected = "3 exceeds 2")]
+    pub fn exceeds_limit(value: u8, label: &str) {
+        assert!(!label.is_empty());
+        assert!(value <= limit, "{} exceeds {}", value, limit);
+    }
+
+    #[test_case(with(1))]
+    pub fn within_limit(value: u8) {
+        assert!(value <= limit);
+    }
+}
+
+// Set by `SITH_SHARD`/`SITH_TAGS`, which filter out the tests driven by others
+fn is_filtering() -> bool {
+    std::env::var_os("SITH_SHARD").is_some() || std::env::var_os("SITH_TAGS").is_some()
+}
+
+#[test_case(skip_if(is_filtering(), "drives tests which may be filtered out"))]
+fn on_failure_receives_failure_before_panic_propagates() {
+    use supports_on_failure::FAILURES;
+
+    assert!(std::panic::catch_unwind(supports_on_failure::within_limit).is_ok());
+    assert!(std::panic::catch_unwind(supports_on_failure::exceeds_limit).is_err());
+
+    let failures = FAILURES.with(| failures | failures.take());
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].test(), "exceeds_limit");
+    assert_eq!(failures[0].message(), "3 exceeds 2");
+    assert_eq!(failures[0].inputs(), [("value", String::from("3")), ("label", String::from("\"three\""))]);
+}
+
+#[test_suite(timeout = "10ms")]
+mod supports_timeout {
+    use sith::test_case;
+
+    #[test]
+    #[should_panic(expected = "timed out after")]
+    fn applies_suite_timeout() {
+        std::thread::sleep(std::time::Duration::from_secs(5));
+    }
+
+    #[test_case(timeout = "5s")]
+    fn overrides_suite_timeout() {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+}
+
+#[test_suite]
+mod supports_repeat {
+    use sith::test_case;
+
+    thread_local! {
+        pub static SETUPS: std::cell::Cell<u32> = const { std::cell::Cell::new(0) };
+        pub static RUNS: std::cell::Cell<u32> = const { std::cell::Cell::new(0) };
+        pub static TEARDOWNS: std::cell::Cell<u32> = const { std::cell::Cell::new(0) };
+    }
+
+    #[setup]
+    fn setup() {
+        SETUPS.with(| setups | setups.set(setups.get() + 1));
+    }
+
+    #[teardown]
+    fn teardown() {
+        TEARDOWNS.with(| teardowns | teardowns.set(teardowns.get() + 1));
+    }
+
+    #[test_case(repeat(16))]
+    pub fn sets_up_per_iteration() {
+        RUNS.with(| runs | runs.set(runs.get() + 1));
+    }
+
+    #[test_case(repeat(16, shared_setup))]
+    pub fn shares_setup_between_iterations() {
+        RUNS.with(| runs | runs.set(runs.get() + 1));
+    }
+
+    #[test_case(repeat(16, shared_setup))]
+    #[ignore = "fails by design, run by repeat_returns_output_after_shared_teardown"]
+    pub fn returns_failed_iteration() -> Result<(), String> {
+        let run: u32 = RUNS.with(| runs | { runs.set(runs.get() + 1); runs.get() });
+        match run {
+            3 => Err(format!("failed on run {}", run)),
+            _ => Ok(())
+        }
+    }
+}
+
+#[test_case(skip_if(is_filtering(), "drives tests which may be filtered out"))]
+fn repeat_runs_setup_per_iteration_unless_shared() {
+    use supports_repeat::{SETUPS, RUNS};
+
+    supports_repeat::sets_up_per_iteration();
+    assert_eq!((SETUPS.with(| setups | setups.take()), RUNS.with(| runs | runs.take())), (16, 16));
+
+    supports_repeat::shares_setup_between_iterations();
+    assert_eq!((SETUPS.with(| setups | setups.take()), RUNS.with(| runs | runs.take())), (1, 16));
+}
+
+#[test_case(skip_if(is_filtering(), "drives tests which may be filtered out"))]
+fn repeat_returns_output_after_shared_teardown() {
+    use supports_repeat::{SETUPS, RUNS, TEARDOWNS};
+
+    TEARDOWNS.with(| teardowns | teardowns.take());
+    assert_eq!(supports_repeat::returns_failed_iteration(), Err(String::from("failed on run 3")));
+    assert_eq!(SETUPS.with(| setups | setups.take()), 1);
+    assert_eq!(RUNS.with(| runs | runs.take()), 3);
+    assert_eq!(TEARDOWNS.with(| teardowns | teardowns.take()), 1);
+}
+
+#[test_suite(serial(shared_state))]
+mod supports_serial {
+    use sith::test_case;
+    use std::sync::atomic::{
+        AtomicBool, Ordering
+    };
+
+    static RUNNING: AtomicBool = AtomicBool::new(false);
+
+    #[setup]
+    fn setup() {
+        assert!(!RUNNING.swap(true, Ordering::SeqCst), "serial tests overlapped");
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    #[teardown]
+    fn teardown() {
+        RUNNING.store(false, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn first() {}
+
+    #[test_case(second)]
+    #[test_case(third)]
+    fn runs_alone() {}
+
+    #[test]
+    #[should_panic(expected = "poisons the lock")]
+    fn poisons_lock() {
+        // Teardown won't run - so the flag must be reset before panicking
+        if RUNNING.swap(false, Ordering::SeqCst) {
+            panic!("poisons the lock");
+        }
+    }
+}
+
+#[test_suite(requires_env("SITH_TEST_NEVER_SET"))]
+mod supports_skip_conditions {
+    use sith::test_case;
+
+    #[setup]
+    fn setup() {
+        panic!("setup should have been skipped");
+    }
+
+    #[test]
+    fn skips_test() {}
+
+    #[test_case(skip_if(false))]
+    fn skips_test_case() {}
+}
+
+#[test_suite]
+mod supports_cfg_attr {
+    use sith::test_case;
+
+    #[setup]
+    fn setup() {
+        let from_setup = true;
+    }
+
+    #[cfg_attr(all(), test_case(enabled))]
+    #[cfg_attr(any(), test_case(disabled))]
+    fn receives_setup() {
+        assert!(from_setup);
+    }
+}
+
+#[test_suite(tags(db))]
+mod supports_tags {
+    use sith::test_case;
+
+    #[test]
+    pub fn tags_tests() {}
+
+    #[test_case(tags(slow))]
+    pub fn merges_tags() {}
+}
+
+#[cfg(feature = "tag-names")]
+#[test]
+fn tags_prefix_suite_names() {
+    supports_tags::tag_db__tags_tests();
+    supports_tags::tag_db__tag_slow__merges_tags();
+}
+
+#[test_suite(tags(fs))]
+mod supports_current_test {
+    use sith::test_case;
+
+    #[setup]
+    fn setup() {
+        let context = sith::current_test!();
+        let dir: String = format!("{}-{}", context.suite().unwrap(), context.name());
+    }
+
+    #[test]
+    fn names_test() {
+        assert!(dir.starts_with("supports_current_test-"));
+        assert_eq!(context.tags(), ["fs"]);
+        assert!(context.qualified_name().starts_with("supports_current_test::"));
+    }
+
+    #[test_case(small, with(each([1, 2])), tags(io))]
+    fn names_case(size: u8) {
+        assert_eq!(context.case(), Some(format!("small_{}", size).as_str()));
+        assert_eq!(context.index(), size as usize - 1);
+        assert_eq!(context.tags(), ["fs", "io"]);
+        assert!(dir.ends_with(context.name()));
+    }
+}